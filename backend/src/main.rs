@@ -2,8 +2,12 @@
 use actix_cors::Cors;
 use actix_web::{App, HttpResponse, HttpServer, Responder, web};
 use common::{
-    LeaderboardEntry, LeaderboardRequest, SubmitScoreRequest, TopUserSchoolEntry,
-    config::MAX_ENTRIES_PER_COURSE,
+    CreateCustomQuizRequest, FinishSessionRequest, FinishSessionResponse, LeaderboardEntry,
+    LeaderboardRequest, School, SchoolAccessRequest, StartSessionRequest, StartSessionResponse,
+    SubmitScoreRequest, TopUserSchoolEntry,
+    config::{MAX_ANSWER_LENGTH, MAX_ENTRIES_PER_COURSE, MAX_SESSION_MINUTES},
+    custom_quiz::{CustomQuiz, CustomQuizPart},
+    quiz::{Difficulty, Quiz, all_answers_correct, generate_questions},
 };
 use sqlx::{
     PgPool,
@@ -56,6 +60,313 @@ async fn setup_database() -> PgPool {
     .await
     .expect("Failed to create index on course, time_seconds, school_id");
 
+    // Schools that have paid for access. A school is added here when it pays,
+    // and redeems its access code (e.g. "MinSkola2024") in the browser.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS schools (
+            id UUID PRIMARY KEY,
+            name VARCHAR(100) NOT NULL,
+            access_code VARCHAR(50) NOT NULL,
+            paid_until TIMESTAMPTZ NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create schools table");
+
+    sqlx::query(
+        r#"
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_schools_access_code
+        ON schools (LOWER(access_code));
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create unique index on schools access_code");
+
+    // Schools that only exist as ids on old leaderboard rows get a random access
+    // code, drawn like share codes, and a 30 day grace period before submissions
+    // are rejected
+    let legacy_schools: Vec<(Uuid, String)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT ON (school_id) school_id, school
+        FROM leaderboard
+        WHERE school_id NOT IN (SELECT id FROM schools)
+        ORDER BY school_id, completed_at DESC
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .expect("Failed to find schools on legacy leaderboard rows");
+
+    for (school_id, name) in legacy_schools {
+        // Without a conflict target this also skips codes that are already taken,
+        // in which case a new code is drawn
+        for _ in 0..5 {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO schools (id, name, access_code, paid_until)
+                VALUES ($1, $2, $3, NOW() + INTERVAL '30 days')
+                ON CONFLICT DO NOTHING
+                "#,
+            )
+            .bind(school_id)
+            .bind(&name)
+            .bind(new_share_code())
+            .execute(&pool)
+            .await
+            .expect("Failed to create schools for legacy leaderboard rows");
+            if result.rows_affected() == 1 {
+                break;
+            }
+        }
+    }
+
+    // Earlier versions derived the legacy access codes from the school id, which
+    // anyone who knows the id can work out, so those are replaced
+    let derived_codes: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT id FROM schools
+        WHERE access_code = 'legacy-' || SUBSTRING(id::text, 1, 8)
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .expect("Failed to find schools with derived access codes");
+
+    for (school_id,) in derived_codes {
+        for _ in 0..5 {
+            let result = sqlx::query(
+                r#"
+                UPDATE schools
+                SET access_code = $2
+                WHERE id = $1
+                AND NOT EXISTS (SELECT 1 FROM schools WHERE LOWER(access_code) = LOWER($2))
+                "#,
+            )
+            .bind(school_id)
+            .bind(new_share_code())
+            .execute(&pool)
+            .await
+            .expect("Failed to replace derived access codes");
+            if result.rows_affected() == 1 {
+                break;
+            }
+        }
+    }
+
+    sqlx::query(
+        r#"
+        DO $$
+        BEGIN
+            IF NOT EXISTS (
+                SELECT 1 FROM pg_constraint WHERE conname = 'leaderboard_school_id_fkey'
+            ) THEN
+                ALTER TABLE leaderboard
+                ADD CONSTRAINT leaderboard_school_id_fkey
+                FOREIGN KEY (school_id) REFERENCES schools (id);
+            END IF;
+        END
+        $$;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add foreign key from leaderboard to schools");
+
+    // Students are the player identity; the leaderboard quota is kept per student
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS students (
+            id UUID PRIMARY KEY,
+            name VARCHAR(100) NOT NULL,
+            school_id UUID NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create students table");
+
+    // Added separately so that tables created before schools existed get it too
+    sqlx::query(
+        r#"
+        DO $$
+        BEGIN
+            IF NOT EXISTS (
+                SELECT 1 FROM pg_constraint WHERE conname = 'students_school_id_fkey'
+            ) THEN
+                ALTER TABLE students
+                ADD CONSTRAINT students_school_id_fkey
+                FOREIGN KEY (school_id) REFERENCES schools (id);
+            END IF;
+        END
+        $$;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add foreign key from students to schools");
+
+    sqlx::query("ALTER TABLE leaderboard ADD COLUMN IF NOT EXISTS student_id UUID")
+        .execute(&pool)
+        .await
+        .expect("Failed to add student_id column to leaderboard");
+
+    // Rows from before student ids existed get one student per (school, name)
+    sqlx::query(
+        r#"
+        INSERT INTO students (id, name, school_id)
+        SELECT gen_random_uuid(), legacy.name, legacy.school_id
+        FROM (
+            SELECT DISTINCT name, school_id
+            FROM leaderboard
+            WHERE student_id IS NULL
+        ) AS legacy
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create students for legacy leaderboard rows");
+
+    sqlx::query(
+        r#"
+        UPDATE leaderboard l
+        SET student_id = s.id
+        FROM students s
+        WHERE l.student_id IS NULL
+          AND s.school_id = l.school_id
+          AND s.name = l.name
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to backfill student_id on legacy leaderboard rows");
+
+    sqlx::query("ALTER TABLE leaderboard ALTER COLUMN student_id SET NOT NULL")
+        .execute(&pool)
+        .await
+        .expect("Failed to make leaderboard.student_id NOT NULL");
+
+    sqlx::query(
+        r#"
+        DO $$
+        BEGIN
+            IF NOT EXISTS (
+                SELECT 1 FROM pg_constraint WHERE conname = 'leaderboard_student_id_fkey'
+            ) THEN
+                ALTER TABLE leaderboard
+                ADD CONSTRAINT leaderboard_student_id_fkey
+                FOREIGN KEY (student_id) REFERENCES students (id);
+            END IF;
+        END
+        $$;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add foreign key from leaderboard to students");
+
+    // Index for the per-student keep-best-N lookup in submit_score
+    sqlx::query(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_leaderboard_course_student
+        ON leaderboard (course, student_id);
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create index on course, student_id");
+
+    // Server-issued quiz sessions. The server measures the time between start and
+    // finish, and submit_score only accepts times from a finished session.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS quiz_sessions (
+            id UUID PRIMARY KEY,
+            course VARCHAR(50) NOT NULL,
+            seed BIGINT NOT NULL,
+            school_id UUID NOT NULL REFERENCES schools (id),
+            student_id UUID NOT NULL,
+            answers TEXT[],
+            time_seconds FLOAT,
+            started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            finished_at TIMESTAMPTZ,
+            submitted_at TIMESTAMPTZ
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create quiz_sessions table");
+
+    // Sessions are graded by regenerating their questions from the seed
+    sqlx::query(
+        r#"
+        ALTER TABLE quiz_sessions
+        ADD COLUMN IF NOT EXISTS passed BOOLEAN NOT NULL DEFAULT FALSE
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add passed column to quiz_sessions");
+
+    // Questions depend on the difficulty, and each difficulty has its own
+    // leaderboard. Everything played before difficulties existed was on Normal.
+    sqlx::query(
+        r#"
+        ALTER TABLE quiz_sessions
+        ADD COLUMN IF NOT EXISTS difficulty VARCHAR(10) NOT NULL DEFAULT 'normal'
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add difficulty column to quiz_sessions");
+
+    sqlx::query(
+        r#"
+        ALTER TABLE leaderboard
+        ADD COLUMN IF NOT EXISTS difficulty VARCHAR(10) NOT NULL DEFAULT 'normal'
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to add difficulty column to leaderboard");
+
+    sqlx::query(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_leaderboard_course_difficulty_time
+        ON leaderboard (course, difficulty, time_seconds ASC, school_id);
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create index on course, difficulty, time_seconds, school_id");
+
+    // Quizzes put together by teachers. The parts are kept as two arrays of the
+    // same length: the course of each part and how many questions it has.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS custom_quizzes (
+            code VARCHAR(10) PRIMARY KEY,
+            title VARCHAR(100) NOT NULL,
+            school_id UUID NOT NULL REFERENCES schools (id),
+            difficulty VARCHAR(10) NOT NULL,
+            seed BIGINT NOT NULL,
+            courses TEXT[] NOT NULL,
+            question_counts INT[] NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create custom_quizzes table");
+
     // Fix any existing NULL values in the table
     sqlx::query(
         r#"
@@ -83,6 +394,15 @@ async fn setup_database() -> PgPool {
     pool
 }
 
+fn normalize_course(course: &str) -> String {
+    let course = course.trim().to_lowercase();
+    if course.is_empty() {
+        "default".to_string()
+    } else {
+        course
+    }
+}
+
 // API handlers
 async fn get_leaderboard(
     db_pool: web::Data<PgPool>,
@@ -96,18 +416,20 @@ async fn get_leaderboard(
     let result = sqlx::query_as!(
         LeaderboardEntry, // Target struct
         r#"
-        SELECT id, name, course, school, school_id, time_seconds, completed_at
+        SELECT id, name, course, school, school_id, student_id, time_seconds, completed_at
         FROM leaderboard
         WHERE course = $1
           AND school = $2    -- Filter by school
           AND school_id = $3 -- Filter by school_id
+          AND difficulty = $5
         ORDER BY time_seconds ASC, completed_at DESC -- Use new index fields
         LIMIT $4             -- Limit parameter is now $4
         "#,
-        &req.course,   // $1
-        &req.school,   // $2
-        req.school_id, // $3 - Uuid doesn't usually need a reference here
-        limit as i64   // $4
+        &req.course,         // $1
+        &req.school,         // $2
+        req.school_id,       // $3 - Uuid doesn't usually need a reference here
+        limit as i64,        // $4
+        req.difficulty.id(), // $5
     )
     .fetch_all(db_pool.get_ref())
     .await;
@@ -128,21 +450,7 @@ async fn submit_score(
     let name = score.name.trim();
     let name = if name.is_empty() { "Anonymous" } else { name }.to_string();
 
-    let course = score.course.trim().to_lowercase();
-    let course = if course.is_empty() {
-        "default"
-    } else {
-        &course
-    }
-    .to_string();
-
-    let school = score.school.trim();
-    let school = if school.is_empty() {
-        "Unknown School"
-    } else {
-        school
-    }
-    .to_string();
+    let course = normalize_course(&score.course);
 
     // Start transaction
     let mut tx = match db_pool.begin().await {
@@ -153,17 +461,113 @@ async fn submit_score(
         }
     };
 
-    // 1. Fetch the user's current scores for this specific course, ordered worst first
+    // 1. Only schools with an active subscription may submit. The registered
+    //    school name is used rather than the one sent by the browser.
+    let school_status = sqlx::query!(
+        r#"
+        SELECT name, paid_until > NOW() AS "active!"
+        FROM schools
+        WHERE id = $1
+        "#,
+        score.school_id, // $1
+    )
+    .fetch_optional(&mut *tx) // Use the transaction
+    .await;
+
+    let school = match school_status {
+        Ok(Some(record)) if record.active => record.name,
+        Ok(Some(_)) => {
+            return HttpResponse::Forbidden().json("The school's subscription has expired.");
+        }
+        Ok(None) => {
+            return HttpResponse::Forbidden().json("Unknown school.");
+        }
+        Err(e) => {
+            eprintln!("Database error checking school: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to check school");
+        }
+    };
+
+    // 2. The time comes from a finished session of this student and course.
+    //    The session must have been started for the same school, and only
+    //    sessions where the server graded every answer as correct count.
+    //    Claiming the session here means it can only be submitted once.
+    //    The score goes on the leaderboard of the difficulty the session was played on.
+    let session = sqlx::query!(
+        r#"
+        UPDATE quiz_sessions
+        SET submitted_at = NOW()
+        WHERE id = $1
+          AND student_id = $2
+          AND course = $3
+          AND school_id = $4
+          AND finished_at IS NOT NULL
+          AND passed
+          AND submitted_at IS NULL
+        RETURNING time_seconds AS "time_seconds!", difficulty
+        "#,
+        score.session_id, // $1
+        score.student_id, // $2
+        course,           // $3
+        score.school_id,  // $4
+    )
+    .fetch_optional(&mut *tx) // Use the transaction
+    .await;
+
+    let (time_seconds, difficulty) = match session {
+        Ok(Some(record)) => (record.time_seconds, record.difficulty),
+        Ok(None) => {
+            return HttpResponse::Forbidden().json("Score is not backed by a passed quiz session.");
+        }
+        Err(e) => {
+            eprintln!("Database error claiming quiz session: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to check quiz session");
+        }
+    };
+
+    // 3. Register the student, or refresh their display name. A student id that is
+    //    already registered at another school returns no row and is rejected.
+    let student = sqlx::query!(
+        r#"
+        INSERT INTO students (id, name, school_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (id) DO UPDATE
+        SET name = EXCLUDED.name
+        WHERE students.school_id = EXCLUDED.school_id
+        RETURNING id
+        "#,
+        score.student_id, // $1
+        name,             // $2
+        score.school_id,  // $3
+    )
+    .fetch_optional(&mut *tx) // Use the transaction
+    .await;
+
+    match student {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return HttpResponse::Forbidden().json("Student belongs to another school.");
+        }
+        Err(e) => {
+            eprintln!("Database error registering student: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to register student");
+        }
+    }
+
+    // 4. Fetch the student's current scores for this specific course and difficulty,
+    //    ordered worst first
     let user_scores = sqlx::query!(
         r#"
         SELECT id, time_seconds
         FROM leaderboard
         WHERE course = $1
-        AND school_id = $2
+        AND student_id = $2
+        AND difficulty = $3
         ORDER BY time_seconds DESC -- Worst score first
         "#,
-        course,          // $1
-        score.school_id, // $2
+        course,           // $1
+        score.student_id, // $2
+        difficulty,       // $3
     )
     .fetch_all(&mut *tx) // Use the transaction
     .await;
@@ -184,32 +588,32 @@ async fn submit_score(
 
     let mut delete_worst = false;
 
-    // 2. Decide whether to insert the new score based on user's limit
+    // 5. Decide whether to insert the new score based on the student's limit
     if current_scores_count < MAX_ENTRIES_PER_COURSE as usize {
         // User has space, always insert
         println!(
-            "User {:?} has {} scores for course '{}' (limit {}). Inserting.",
-            score.school_id, current_scores_count, course, MAX_ENTRIES_PER_COURSE
+            "Student {:?} has {} scores for course '{}' (limit {}). Inserting.",
+            score.student_id, current_scores_count, course, MAX_ENTRIES_PER_COURSE
         );
         // No deletion needed yet
     } else {
         // User is at the limit, check if the new score is better than their worst
         match worst_score_time {
-            Some(worst_time) if score.time_seconds < worst_time => {
+            Some(worst_time) if time_seconds < worst_time => {
                 // New score is better than the worst, allow insertion and mark worst for deletion
                 println!(
-                    "User {:?} at limit for course '{}'. New score {:.2} is better than worst {:.2}. Replacing.",
-                    score.school_id, course, score.time_seconds, worst_time
+                    "Student {:?} at limit for course '{}'. New score {:.2} is better than worst {:.2}. Replacing.",
+                    score.student_id, course, time_seconds, worst_time
                 );
                 delete_worst = true; // Mark the user's own worst score for deletion
             }
             _ => {
                 // User is at limit, and the new score is not better than their worst
                 println!(
-                    "User {:?} at limit for course '{}'. New score {:.2} is not better than worst {:.2}. Rejecting.",
-                    score.school_id,
+                    "Student {:?} at limit for course '{}'. New score {:.2} is not better than worst {:.2}. Rejecting.",
+                    score.student_id,
                     course,
-                    score.time_seconds,
+                    time_seconds,
                     worst_score_time.unwrap_or(f64::INFINITY)
                 );
                 // Don't insert, return a conflict/rejection message
@@ -221,19 +625,21 @@ async fn submit_score(
         }
     }
 
-    // 3. Insert the new score if allowed
+    // 6. Insert the new score if allowed
     let insert_result = sqlx::query!(
         r#"
-        INSERT INTO leaderboard (name, course, school, school_id, time_seconds, completed_at)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO leaderboard (name, course, school, school_id, student_id, time_seconds, completed_at, difficulty)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         RETURNING id
         "#,
         name,
         course,
         school,
         score.school_id,
-        score.time_seconds,
-        Utc::now()
+        score.student_id,
+        time_seconds,
+        Utc::now(),
+        difficulty
     )
     .fetch_one(&mut *tx) // Use the transaction
     .await;
@@ -247,17 +653,17 @@ async fn submit_score(
         }
     };
 
-    // 4. Delete the *user's own* worst score *if* needed (if delete_worst is true)
+    // 7. Delete the *student's own* worst score *if* needed (if delete_worst is true)
     if delete_worst {
         if let Some(id_to_delete) = worst_score_id {
             println!(
-                "Deleting user's ({:?}) worst score (ID: {}) for course '{}'",
-                score.school_id, id_to_delete, course
+                "Deleting student's ({:?}) worst score (ID: {}) for course '{}'",
+                score.student_id, id_to_delete, course
             );
             let delete_user_worst_result = sqlx::query!(
-                "DELETE FROM leaderboard WHERE id = $1 AND school_id = $2", // Double-check school_id for safety
+                "DELETE FROM leaderboard WHERE id = $1 AND student_id = $2", // Double-check student_id for safety
                 id_to_delete,
-                score.school_id
+                score.student_id
             )
             .execute(&mut *tx) // Use the transaction
             .await;
@@ -272,8 +678,8 @@ async fn submit_score(
                 Ok(_) => {
                     // This case (0 rows affected) might happen if something changed between the check and delete, though unlikely within a transaction. Log it.
                     eprintln!(
-                        "Warning: Attempted to delete user's worst score ID {} but it was not found or did not match school_id {:?}.",
-                        id_to_delete, score.school_id
+                        "Warning: Attempted to delete student's worst score ID {} but it was not found or did not match student_id {:?}.",
+                        id_to_delete, score.student_id
                     );
                 }
                 Err(e) => {
@@ -286,18 +692,18 @@ async fn submit_score(
         } else {
             // Should not happen if delete_worst is true, but log defensively
             eprintln!(
-                "Warning: delete_worst was true, but no worst_score_id found for user {:?} course {}.",
-                score.school_id, course
+                "Warning: delete_worst was true, but no worst_score_id found for student {:?} course {}.",
+                score.student_id, course
             );
         }
     }
 
-    // 5. REMOVED - No overall leaderboard cleanup in this operation.
+    // 8. REMOVED - No overall leaderboard cleanup in this operation.
     //    The leaderboard might grow indefinitely for a course if many users submit scores.
     //    Cleanup would need to be handled separately (e.g., a background job or admin task)
     //    if you want to limit the *total* size of the leaderboard per course.
 
-    // 6. Commit the transaction
+    // 9. Commit the transaction
     match tx.commit().await {
         Ok(_) => HttpResponse::Created().json(serde_json::json!({ "id": new_id })),
         Err(e) => {
@@ -307,6 +713,339 @@ async fn submit_score(
     }
 }
 
+// Issues a session id and question seed, and starts the server-side clock
+async fn start_session(
+    db_pool: web::Data<PgPool>,
+    req: web::Json<StartSessionRequest>,
+) -> impl Responder {
+    // The course decides which questions the seed generates, so it has to be known.
+    // A custom quiz brings its own seed and difficulty, so every student gets the
+    // questions the teacher picked.
+    let (course, seed, difficulty) = match CustomQuiz::code_from_course_id(&req.course) {
+        Some(code) => match load_custom_quiz(db_pool.get_ref(), &code).await {
+            Ok(Some(custom_quiz)) => (
+                custom_quiz.course_id(),
+                custom_quiz.seed,
+                custom_quiz.difficulty,
+            ),
+            Ok(None) => return HttpResponse::BadRequest().json("Unknown custom quiz."),
+            Err(e) => {
+                eprintln!("Database error loading custom quiz: {}", e);
+                return HttpResponse::InternalServerError().json("Failed to start quiz session");
+            }
+        },
+        None => match Quiz::from_course_id(&req.course) {
+            Some(quiz) => (
+                quiz.course_id(),
+                Uuid::new_v4().as_u64_pair().0,
+                req.difficulty,
+            ),
+            None => return HttpResponse::BadRequest().json("Unknown course."),
+        },
+    };
+    let session_id = Uuid::new_v4();
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO quiz_sessions (id, course, seed, school_id, student_id, difficulty)
+        SELECT $1, $2, $3, id, $5, $6
+        FROM schools
+        WHERE id = $4 AND paid_until > NOW()
+        "#,
+        session_id,      // $1
+        course,          // $2
+        seed as i64,     // $3 - Stored bit for bit in a BIGINT
+        req.school_id,   // $4
+        req.student_id,  // $5
+        difficulty.id(), // $6
+    )
+    .execute(db_pool.get_ref())
+    .await;
+
+    match result {
+        Ok(result) if result.rows_affected() == 1 => {
+            HttpResponse::Created().json(StartSessionResponse { session_id, seed })
+        }
+        Ok(_) => HttpResponse::Forbidden().json("Unknown school or expired subscription."),
+        Err(e) => {
+            eprintln!("Database error starting quiz session: {}", e);
+            HttpResponse::InternalServerError().json("Failed to start quiz session")
+        }
+    }
+}
+
+// Stops the server-side clock and grades the answers given in the session
+async fn finish_session(
+    db_pool: web::Data<PgPool>,
+    req: web::Json<FinishSessionRequest>,
+) -> impl Responder {
+    if req.answers.is_empty() {
+        return HttpResponse::BadRequest().json("A finished session needs answers.");
+    }
+    if req
+        .answers
+        .iter()
+        .any(|answer| answer.chars().count() > MAX_ANSWER_LENGTH)
+    {
+        return HttpResponse::BadRequest().json("An answer is too long.");
+    }
+
+    let session = sqlx::query!(
+        r#"
+        SELECT course, seed, difficulty
+        FROM quiz_sessions
+        WHERE id = $1
+          AND finished_at IS NULL
+          AND started_at > NOW() - make_interval(mins => $2)
+        "#,
+        req.session_id,             // $1
+        MAX_SESSION_MINUTES as i32, // $2
+    )
+    .fetch_optional(db_pool.get_ref())
+    .await;
+
+    let session = match session {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            return HttpResponse::NotFound()
+                .json("Unknown, already finished or expired quiz session.");
+        }
+        Err(e) => {
+            eprintln!("Database error loading quiz session: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to finish quiz session");
+        }
+    };
+
+    // Regenerate the questions the browser showed and grade the answers against them
+    let difficulty = Difficulty::from_id(&session.difficulty).unwrap_or_default();
+    let seed = session.seed as u64;
+    let questions = match CustomQuiz::code_from_course_id(&session.course) {
+        Some(code) => match load_custom_quiz(db_pool.get_ref(), &code).await {
+            Ok(custom_quiz) => custom_quiz.map(|custom_quiz| custom_quiz.generate_questions(seed)),
+            Err(e) => {
+                eprintln!("Database error loading custom quiz: {}", e);
+                return HttpResponse::InternalServerError().json("Failed to finish quiz session");
+            }
+        },
+        None => Quiz::from_course_id(&session.course)
+            .map(|quiz| generate_questions(quiz, quiz.number_of_questions(), seed, difficulty)),
+    };
+    let passed = questions.is_some_and(|questions| all_answers_correct(&questions, &req.answers));
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE quiz_sessions
+        SET finished_at = NOW(),
+            answers = $2,
+            passed = $3,
+            time_seconds = EXTRACT(EPOCH FROM (NOW() - started_at))
+        WHERE id = $1
+          AND finished_at IS NULL
+        RETURNING time_seconds AS "time_seconds!"
+        "#,
+        req.session_id, // $1
+        &req.answers,   // $2
+        passed,         // $3
+    )
+    .fetch_optional(db_pool.get_ref())
+    .await;
+
+    match result {
+        Ok(Some(record)) => HttpResponse::Ok().json(FinishSessionResponse {
+            time_seconds: record.time_seconds,
+            passed,
+        }),
+        Ok(None) => {
+            HttpResponse::NotFound().json("Unknown, already finished or expired quiz session.")
+        }
+        Err(e) => {
+            eprintln!("Database error finishing quiz session: {}", e);
+            HttpResponse::InternalServerError().json("Failed to finish quiz session")
+        }
+    }
+}
+
+// Share codes leave out letters and digits that are easy to mix up, like O and 0
+const SHARE_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const SHARE_CODE_LENGTH: usize = 6;
+
+fn new_share_code() -> String {
+    // 32 symbols divide 256 evenly, so every symbol is equally likely
+    Uuid::new_v4().as_bytes()[..SHARE_CODE_LENGTH]
+        .iter()
+        .map(|byte| SHARE_CODE_ALPHABET[*byte as usize % SHARE_CODE_ALPHABET.len()] as char)
+        .collect()
+}
+
+async fn load_custom_quiz(db_pool: &PgPool, code: &str) -> Result<Option<CustomQuiz>, sqlx::Error> {
+    let record = sqlx::query!(
+        r#"
+        SELECT code, title, difficulty, seed, courses, question_counts
+        FROM custom_quizzes
+        WHERE code = UPPER($1)
+        "#,
+        code.trim(), // $1
+    )
+    .fetch_optional(db_pool)
+    .await?;
+
+    Ok(record.map(|record| CustomQuiz {
+        code: record.code,
+        title: record.title,
+        difficulty: Difficulty::from_id(&record.difficulty).unwrap_or_default(),
+        seed: record.seed as u64,
+        parts: record
+            .courses
+            .into_iter()
+            .zip(record.question_counts)
+            .map(|(course, questions)| CustomQuizPart {
+                course,
+                questions: questions.max(0) as usize,
+            })
+            .collect(),
+    }))
+}
+
+// Stores a teacher's custom quiz under a new share code
+async fn create_custom_quiz(
+    db_pool: web::Data<PgPool>,
+    req: web::Json<CreateCustomQuizRequest>,
+) -> impl Responder {
+    let title = req.title.trim();
+    if title.is_empty() || title.chars().count() > 100 {
+        return HttpResponse::BadRequest()
+            .json("A custom quiz needs a title of at most 100 characters.");
+    }
+    if let Err(message) = CustomQuiz::validate_parts(&req.parts) {
+        return HttpResponse::BadRequest().json(message);
+    }
+
+    // Course ids are stored the way the quizzes name them
+    let parts: Vec<CustomQuizPart> = req
+        .parts
+        .iter()
+        .filter_map(|part| {
+            Quiz::from_course_id(&part.course).map(|quiz| CustomQuizPart {
+                course: quiz.course_id(),
+                questions: part.questions,
+            })
+        })
+        .collect();
+    let courses: Vec<String> = parts.iter().map(|part| part.course.clone()).collect();
+    let Ok(question_counts) = parts
+        .iter()
+        .map(|part| i32::try_from(part.questions))
+        .collect::<Result<Vec<i32>, _>>()
+    else {
+        return HttpResponse::BadRequest().json("Too many questions in a part.");
+    };
+    let seed = req.seed.unwrap_or_else(|| Uuid::new_v4().as_u64_pair().0);
+
+    // Only schools with an active subscription may create quizzes
+    let school_active = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM schools WHERE id = $1 AND paid_until > NOW()) AS "active!""#,
+        req.school_id, // $1
+    )
+    .fetch_one(db_pool.get_ref())
+    .await;
+
+    match school_active {
+        Ok(true) => {}
+        Ok(false) => {
+            return HttpResponse::Forbidden().json("Unknown school or expired subscription.");
+        }
+        Err(e) => {
+            eprintln!("Database error checking school: {}", e);
+            return HttpResponse::InternalServerError().json("Failed to create custom quiz");
+        }
+    }
+
+    // A new code is drawn in the unlikely case that one is already taken
+    for _ in 0..5 {
+        let code = new_share_code();
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO custom_quizzes (code, title, school_id, difficulty, seed, courses, question_counts)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (code) DO NOTHING
+            "#,
+            code,                // $1
+            title,               // $2
+            req.school_id,       // $3
+            req.difficulty.id(), // $4
+            seed as i64,         // $5 - Stored bit for bit in a BIGINT
+            &courses,            // $6
+            &question_counts,    // $7
+        )
+        .execute(db_pool.get_ref())
+        .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 1 => {
+                return HttpResponse::Created().json(CustomQuiz {
+                    code,
+                    title: title.to_string(),
+                    difficulty: req.difficulty,
+                    seed,
+                    parts,
+                });
+            }
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("Database error creating custom quiz: {}", e);
+                return HttpResponse::InternalServerError().json("Failed to create custom quiz");
+            }
+        }
+    }
+
+    HttpResponse::InternalServerError().json("Failed to find a free share code")
+}
+
+// Looks up a custom quiz by its share code so the browser can run it
+async fn get_custom_quiz(db_pool: web::Data<PgPool>, code: web::Path<String>) -> impl Responder {
+    match load_custom_quiz(db_pool.get_ref(), &code).await {
+        Ok(Some(custom_quiz)) => HttpResponse::Ok().json(custom_quiz),
+        Ok(None) => HttpResponse::NotFound().json("Unknown custom quiz."),
+        Err(e) => {
+            eprintln!("Database error loading custom quiz: {}", e);
+            HttpResponse::InternalServerError().json("Failed to load custom quiz")
+        }
+    }
+}
+
+// Trades a school's access code for its id and name
+async fn redeem_access_code(
+    db_pool: web::Data<PgPool>,
+    req: web::Json<SchoolAccessRequest>,
+) -> impl Responder {
+    let access_code = req.access_code.trim();
+    if access_code.is_empty() {
+        return HttpResponse::BadRequest().json("Access code is required.");
+    }
+
+    let result = sqlx::query_as!(
+        School,
+        r#"
+        SELECT id AS school_id, name, paid_until
+        FROM schools
+        WHERE LOWER(access_code) = LOWER($1)
+        "#,
+        access_code, // $1
+    )
+    .fetch_optional(db_pool.get_ref())
+    .await;
+
+    match result {
+        Ok(Some(school)) if school.paid_until > Utc::now() => HttpResponse::Ok().json(school),
+        Ok(Some(_)) => HttpResponse::Forbidden().json("The access code has expired."),
+        Ok(None) => HttpResponse::NotFound().json("Unknown access code."),
+        Err(e) => {
+            eprintln!("Database error redeeming access code: {}", e);
+            HttpResponse::InternalServerError().json("Failed to check access code")
+        }
+    }
+}
+
 #[derive(serde::Deserialize, Debug, Default)] // Query parameters for fetching top users
 pub struct GetTopUsersBySchoolQuery {
     pub school: String,
@@ -324,32 +1063,34 @@ async fn get_top_users_by_school(
     let query_str = r#"
     WITH UserCourseRanks AS (
         SELECT
-            l.name,
+            l.student_id,
             l.school,
             l.school_id,
             l.course,
+            l.difficulty,
             l.completed_at,
             l.time_seconds,
-            ROW_NUMBER() OVER (PARTITION BY l.course ORDER BY l.time_seconds ASC, l.completed_at ASC) as rank_in_course
+            ROW_NUMBER() OVER (PARTITION BY l.course, l.difficulty ORDER BY l.time_seconds ASC, l.completed_at ASC) as rank_in_course
         FROM leaderboard l
     )
     SELECT
-        ucr.name,
+        s.name,
         ucr.school,
-        COUNT(DISTINCT ucr.course) AS leaderboard_count,
+        COUNT(DISTINCT (ucr.course, ucr.difficulty)) AS leaderboard_count,
         COALESCE(SUM(CASE WHEN ucr.rank_in_course = 1 THEN 1 ELSE 0 END), 0) AS gold_medals,
         COALESCE(SUM(CASE WHEN ucr.rank_in_course = 2 THEN 1 ELSE 0 END), 0) AS silver_medals,
         COALESCE(SUM(CASE WHEN ucr.rank_in_course = 3 THEN 1 ELSE 0 END), 0) AS bronze_medals,
         COALESCE(SUM(CASE WHEN ucr.rank_in_course > 3 THEN 1 ELSE 0 END), 0) AS generic_medals
     FROM UserCourseRanks ucr
+    JOIN students s ON s.id = ucr.student_id
     WHERE ucr.school_id = $1 AND ucr.school = $2
-    GROUP BY ucr.name, ucr.school
+    GROUP BY ucr.student_id, s.name, ucr.school
     ORDER BY leaderboard_count DESC, MIN(ucr.completed_at) ASC
     LIMIT $3;
 "#;
 
     let result = sqlx::query_as::<_, TopUserSchoolEntry>(query_str)
-        .bind(&req.school_id) // $1: school_id (Uuid of the school)
+        .bind(req.school_id) // $1: school_id (Uuid of the school)
         .bind(&req.school) // $2: school_name (String, name of the school)
         .bind(limit) // $3: limit (i64, number of top users to return)
         .fetch_all(db_pool.get_ref())
@@ -384,6 +1125,11 @@ async fn main() -> std::io::Result<()> {
                 web::scope("/api")
                     .route("/leaderboard", web::get().to(get_leaderboard))
                     .route("/submit", web::post().to(submit_score))
+                    .route("/school/access", web::post().to(redeem_access_code))
+                    .route("/session/start", web::post().to(start_session))
+                    .route("/session/finish", web::post().to(finish_session))
+                    .route("/custom_quiz", web::post().to(create_custom_quiz))
+                    .route("/custom_quiz/{code}", web::get().to(get_custom_quiz))
                     .route(
                         "/top_users_by_school",
                         web::get().to(get_top_users_by_school),
@@ -0,0 +1,357 @@
+use crate::{
+    number::{format_number, parse_number, parse_rational},
+    polynomial::Polynomial,
+    quizzes::{
+        format_duration, from_roman, gcd, parse_duration, parse_time_phrase, time_phrase, to_roman,
+    },
+};
+use std::fmt::{self, Display};
+
+// The expected answer to a question. Each kind knows how to read what the
+// student typed or clicked, and how to show itself when the student got it wrong.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    // Checked against `value` with the question's tolerance policy
+    Decimal {
+        value: f64,
+        tolerance: Tolerance,
+    },
+    // Accepted when equal and written in the given form, e.g. 2/4 or 0,5 for 1/2
+    // with `FractionForm::Any`. With `lowest_terms` the fraction can't be reduced further.
+    Fraction {
+        numerator: i64,
+        denominator: i64,
+        form: FractionForm,
+        lowest_terms: bool,
+    },
+    // 24-hour clock, typed as HH:MM or HHMM
+    ClockTime {
+        hour: u8,
+        minute: u8,
+    },
+    // A length of time in minutes, e.g. "1 h 25 min", "85 min" or "1:25"
+    Duration(u32),
+    // The time said in words, e.g. "kvart över tre" for 15:15. The part of the day
+    // may be left out, but has to be right if it is said.
+    ClockPhrase {
+        hour: u8,
+        minute: u8,
+    },
+    // Typed in the standard spelling, in either case, e.g. "xiv" for 14 but not "XIIII"
+    RomanNumeral(u16),
+    // Accepted when algebraically equal, e.g. "2 + 6x" for "6x + 2"
+    Expression(String),
+    // Index of the correct choice, and the text shown on its button
+    Choice {
+        index: usize,
+        label: String,
+    },
+    // Every choice that should be picked, by index in ascending order, answered
+    // as the picked indices separated by commas, e.g. "0,2"
+    Choices {
+        indices: Vec<usize>,
+        label: String,
+    },
+    // Several choices are right on their own, and picking any one of them is accepted
+    AnyChoice {
+        indices: Vec<usize>,
+        label: String,
+    },
+}
+
+// How a fraction answer has to be written
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FractionForm {
+    // Any equal number, decimals included
+    Any,
+    // A fraction, mixed number or whole number, e.g. 7/3 or 2 1/3
+    Fraction,
+    // A fraction without a whole part, e.g. 7/3
+    Improper,
+    // A whole part and a proper fraction, e.g. 2 1/3
+    Mixed,
+}
+
+impl FractionForm {
+    // Whether `input`, read as numerator/denominator, is written in this form
+    fn accepts(&self, input: &str, numerator: i64, denominator: i64) -> bool {
+        let fraction = !input.contains([',', '.']);
+        let mixed = input
+            .split_once('/')
+            .is_some_and(|(whole_and_numerator, _)| {
+                whole_and_numerator.trim().contains(char::is_whitespace)
+            });
+        match self {
+            FractionForm::Any => true,
+            FractionForm::Fraction => fraction,
+            FractionForm::Improper => fraction && !mixed,
+            // Values below one have no whole part to write
+            FractionForm::Mixed => fraction && (mixed || numerator.abs() < denominator),
+        }
+    }
+}
+
+// How exactly a decimal answer has to match the computed value
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    Exact,
+    // The value rounded to this many decimals with the given rule
+    Rounded { decimals: u32, rule: RoundingRule },
+    SignificantFigures(u32),
+    // Largest accepted difference as a share of the value, e.g. 0.01 for 1%
+    RelativeError(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingRule {
+    Nearest,
+    Down,
+    Up,
+}
+
+impl Tolerance {
+    // The usual policy: round to the nearest value with this many decimals
+    pub fn decimals(decimals: u32) -> Self {
+        Tolerance::Rounded {
+            decimals,
+            rule: RoundingRule::Nearest,
+        }
+    }
+
+    // The answer the student is expected to give for `value`
+    pub fn expected(&self, value: f64) -> f64 {
+        match self {
+            Tolerance::Exact | Tolerance::RelativeError(_) => value,
+            Tolerance::Rounded { decimals, rule } => round_to(value, *decimals as i32, *rule),
+            Tolerance::SignificantFigures(figures) => {
+                if value == 0.0 {
+                    return 0.0;
+                }
+                let magnitude = value.abs().log10().floor() as i32;
+                round_to(
+                    value,
+                    *figures as i32 - 1 - magnitude,
+                    RoundingRule::Nearest,
+                )
+            }
+        }
+    }
+
+    pub fn accepts(&self, value: f64, given: f64) -> bool {
+        let allowed = match self {
+            Tolerance::RelativeError(share) => share * value.abs(),
+            _ => 0.0,
+        };
+        // A little slack for floating point noise on top of the policy
+        (given - self.expected(value)).abs() <= allowed + 1e-9 * value.abs().max(1.0)
+    }
+
+    // Tells the student the required precision, shown after the question
+    pub fn instruction(&self) -> Option<String> {
+        match self {
+            Tolerance::Exact => None,
+            Tolerance::Rounded { decimals, rule } => {
+                let direction = match rule {
+                    RoundingRule::Nearest => "",
+                    RoundingRule::Down => " nedåt",
+                    RoundingRule::Up => " uppåt",
+                };
+                let precision = match decimals {
+                    0 => "heltal".to_string(),
+                    1 => "en decimal".to_string(),
+                    n => format!("{} decimaler", n),
+                };
+                Some(format!("Avrunda{} till {}.", direction, precision))
+            }
+            Tolerance::SignificantFigures(figures) => {
+                Some(format!("Svara med {} värdesiffror.", figures))
+            }
+            Tolerance::RelativeError(share) => Some(format!(
+                "Svaret får avvika högst {}% från det exakta värdet.",
+                share * 100.0
+            )),
+        }
+    }
+}
+
+fn round_to(value: f64, decimals: i32, rule: RoundingRule) -> f64 {
+    let factor = 10_f64.powi(decimals);
+    let scaled = value * factor;
+    // Nudge values like 1.15 * 10 = 11.4999.. back onto the half they represent
+    let rounded = match rule {
+        RoundingRule::Nearest => (scaled + scaled.signum() * 1e-9).round(),
+        RoundingRule::Down => (scaled + 1e-9).floor(),
+        RoundingRule::Up => (scaled - 1e-9).ceil(),
+    };
+    rounded / factor
+}
+
+impl Answer {
+    pub fn decimal(value: f64, tolerance: Tolerance) -> Self {
+        Answer::Decimal { value, tolerance }
+    }
+
+    pub fn instruction(&self) -> Option<String> {
+        match self {
+            Answer::Decimal { tolerance, .. } => tolerance.instruction(),
+            _ => None,
+        }
+    }
+
+    // The last step of an explanation, "expression = value", followed by
+    // "≈ answer" when the answer is the value rounded
+    pub fn equation(&self, expression: &str, value: f64) -> String {
+        let exact = format_number(value);
+        let answer = self.to_string();
+        if exact == answer {
+            format!("{} = {}", expression, exact)
+        } else {
+            format!("{} = {} ≈ {}", expression, exact, answer)
+        }
+    }
+
+    pub fn check(&self, input: &str) -> bool {
+        let input = input.trim();
+        match self {
+            Answer::Integer(expected) => parse_integer(input) == Some(*expected),
+            Answer::Decimal { value, tolerance } => {
+                parse_number(input).is_some_and(|given| tolerance.accepts(*value, given))
+            }
+            Answer::Fraction {
+                numerator,
+                denominator,
+                form,
+                lowest_terms,
+            } => parse_rational(input).is_some_and(|(given_numerator, given_denominator)| {
+                given_numerator as i128 * *denominator as i128
+                    == *numerator as i128 * given_denominator as i128
+                    && form.accepts(input, given_numerator, given_denominator)
+                    && (!lowest_terms || is_lowest_terms(given_numerator, given_denominator))
+            }),
+            Answer::ClockTime { hour, minute } => parse_clock_time(input) == Some((*hour, *minute)),
+            Answer::Duration(minutes) => parse_duration(input) == Some(*minutes),
+            Answer::ClockPhrase { hour, minute } => {
+                parse_time_phrase(input).is_some_and(|(said_hour, said_minute, is_afternoon)| {
+                    said_hour % 12 == hour % 12
+                        && said_minute == *minute
+                        && is_afternoon.is_none_or(|is_afternoon| is_afternoon == (*hour >= 12))
+                })
+            }
+            Answer::RomanNumeral(value) => from_roman(input) == Some(*value),
+            Answer::Expression(expected) => {
+                match (Polynomial::parse(input), Polynomial::parse(expected)) {
+                    (Some(given), Some(expected)) => given.equivalent(&expected),
+                    // Answers that aren't polynomials are compared as text
+                    _ => normalize_expression(input) == normalize_expression(expected),
+                }
+            }
+            Answer::Choice { index, .. } => input.parse::<usize>() == Ok(*index),
+            Answer::Choices { indices, .. } => parse_indices(input).as_ref() == Some(indices),
+            Answer::AnyChoice { indices, .. } => input
+                .parse::<usize>()
+                .is_ok_and(|index| indices.contains(&index)),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            // Any value close enough is right, so the exact one is only shown to four
+            // decimals, or four significant figures for small values
+            Answer::Decimal {
+                value,
+                tolerance: Tolerance::RelativeError(_),
+            } => write!(f, "{}", format_number(*value)),
+            Answer::Decimal { value, tolerance } => write!(f, "{}", tolerance.expected(*value)),
+            Answer::Fraction {
+                numerator,
+                denominator,
+                form: FractionForm::Mixed,
+                ..
+            } if numerator.abs() > *denominator && numerator % denominator != 0 => write!(
+                f,
+                "{} {}/{}",
+                numerator / denominator,
+                (numerator % denominator).abs(),
+                denominator
+            ),
+            Answer::Fraction {
+                numerator,
+                denominator,
+                ..
+            } if *denominator == 1 => write!(f, "{}", numerator),
+            Answer::Fraction {
+                numerator,
+                denominator,
+                ..
+            } => write!(f, "{}/{}", numerator, denominator),
+            Answer::ClockTime { hour, minute } => write!(f, "{:02}:{:02}", hour, minute),
+            Answer::Duration(minutes) => write!(f, "{}", format_duration(*minutes)),
+            Answer::ClockPhrase { hour, minute } => write!(f, "{}", time_phrase(*hour, *minute)),
+            Answer::RomanNumeral(value) => write!(f, "{}", to_roman(*value)),
+            Answer::Expression(expression) => write!(f, "{}", expression),
+            Answer::Choice { label, .. }
+            | Answer::Choices { label, .. }
+            | Answer::AnyChoice { label, .. } => write!(f, "{}", label),
+        }
+    }
+}
+
+// Whole numbers may also be typed as e.g. "12,0" or "24/2"
+fn parse_integer(input: &str) -> Option<i64> {
+    let (numerator, denominator) = parse_rational(input)?;
+    (numerator % denominator == 0).then_some(numerator / denominator)
+}
+
+// Whole parts don't matter, since 2 2/6 is 14/6 and shares its factors with 2/6
+fn is_lowest_terms(numerator: i64, denominator: i64) -> bool {
+    match (i32::try_from(numerator.abs()), i32::try_from(denominator)) {
+        (Ok(numerator), Ok(denominator)) => gcd(numerator, denominator) == 1,
+        _ => false,
+    }
+}
+
+// Reads a list of choice indices in any order, e.g. "2,0", as [0, 2]. An empty
+// input means that no choice was picked.
+fn parse_indices(input: &str) -> Option<Vec<usize>> {
+    let mut indices = input
+        .split(',')
+        .map(str::trim)
+        .filter(|index| !index.is_empty())
+        .map(|index| index.parse().ok())
+        .collect::<Option<Vec<usize>>>()?;
+    indices.sort_unstable();
+    indices.dedup();
+    Some(indices)
+}
+
+// Reads HH:MM, or HHMM/HMM without a colon, e.g. "7:15", "0715" or "715"
+fn parse_clock_time(input: &str) -> Option<(u8, u8)> {
+    let (hour, minute) = match input.split_once(':') {
+        Some((hour, minute)) => {
+            if hour.is_empty() || minute.is_empty() {
+                return None; // Malformed input like ":30" or "10:"
+            }
+            (hour.parse::<u8>().ok()?, minute.parse::<u8>().ok()?)
+        }
+        None => {
+            if !input.chars().all(|c| c.is_ascii_digit()) || !(3..=4).contains(&input.len()) {
+                return None;
+            }
+            let time = input.parse::<u16>().ok()?;
+            ((time / 100) as u8, (time % 100) as u8)
+        }
+    };
+    (hour < 24 && minute < 60).then_some((hour, minute))
+}
+
+fn normalize_expression(expression: &str) -> String {
+    expression
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -3,3 +3,7 @@ pub const API_BASE_URL: &str = "http://127.0.0.1:8080";
 #[cfg(not(debug_assertions))]
 pub const API_BASE_URL: &str = "";
 pub const MAX_ENTRIES_PER_COURSE: i64 = 10;
+pub const MAX_SESSION_MINUTES: i64 = 60;
+pub const MAX_CUSTOM_QUIZ_QUESTIONS: usize = 50;
+// Longest answer the backend grades, in characters. No right answer comes close.
+pub const MAX_ANSWER_LENGTH: usize = 100;
@@ -0,0 +1,142 @@
+use crate::{
+    config::MAX_CUSTOM_QUIZ_QUESTIONS,
+    quiz::{Difficulty, QuestionBox, Quiz},
+};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+// Custom quizzes are stored under courses like "egen:k7xq2m", which keeps their
+// sessions and leaderboards apart from the built-in quizzes
+const COURSE_PREFIX: &str = "egen:";
+
+// A quiz put together by a teacher, e.g. 5 area, 5 circumference and 3 median
+// questions on Hard. It is shared with the class by its code, and everyone who
+// plays it gets the same questions since the seed is part of the quiz.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomQuiz {
+    pub code: String,
+    pub title: String,
+    pub difficulty: Difficulty,
+    pub seed: u64,
+    pub parts: Vec<CustomQuizPart>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomQuizPart {
+    pub course: String, // Course id of the quiz the questions come from
+    pub questions: usize,
+}
+
+impl CustomQuiz {
+    // The course name stored with leaderboard entries and quiz sessions
+    pub fn course_id(&self) -> String {
+        format!("{}{}", COURSE_PREFIX, self.code.to_lowercase())
+    }
+
+    // The share code of a custom quiz course, or None for the built-in quizzes
+    pub fn code_from_course_id(course: &str) -> Option<String> {
+        let code = course
+            .trim()
+            .to_lowercase()
+            .strip_prefix(COURSE_PREFIX)?
+            .to_uppercase();
+        (!code.is_empty()).then_some(code)
+    }
+
+    pub fn number_of_questions(&self) -> usize {
+        self.parts
+            .iter()
+            .fold(0, |total, part| total.saturating_add(part.questions))
+    }
+
+    // The questions of each part in the order they were added. Like
+    // `generate_questions`, the same seed always gives the same questions.
+    pub fn generate_questions(&self, seed: u64) -> Vec<QuestionBox> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        self.parts
+            .iter()
+            .filter_map(|part| {
+                Quiz::from_course_id(&part.course).map(|quiz| (quiz, part.questions))
+            })
+            .flat_map(|(quiz, count)| std::iter::repeat_n(quiz, count))
+            .take(MAX_CUSTOM_QUIZ_QUESTIONS)
+            .filter_map(|quiz| quiz.generate_question(&mut rng, self.difficulty))
+            .collect()
+    }
+
+    // Checks the parts a teacher sent in, with a message that can be shown to them
+    pub fn validate_parts(parts: &[CustomQuizPart]) -> Result<(), String> {
+        if parts.is_empty() {
+            return Err("A custom quiz needs at least one part.".to_string());
+        }
+        if let Some(part) = parts
+            .iter()
+            .find(|part| Quiz::from_course_id(&part.course).is_none())
+        {
+            return Err(format!("Unknown course '{}'.", part.course));
+        }
+        if parts.iter().any(|part| part.questions == 0) {
+            return Err("Every part needs at least one question.".to_string());
+        }
+        // Each part is checked on its own first, so huge counts can't wrap the total
+        let total = parts.iter().try_fold(0_usize, |total, part| {
+            (part.questions <= MAX_CUSTOM_QUIZ_QUESTIONS)
+                .then(|| total.checked_add(part.questions))
+                .flatten()
+        });
+        if total.is_none_or(|total| total > MAX_CUSTOM_QUIZ_QUESTIONS) {
+            return Err(format!(
+                "A custom quiz can have at most {} questions.",
+                MAX_CUSTOM_QUIZ_QUESTIONS
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(questions: usize) -> CustomQuizPart {
+        CustomQuizPart {
+            course: Quiz::Area.course_id(),
+            questions,
+        }
+    }
+
+    #[test]
+    fn accepts_parts_within_the_limit() {
+        assert_eq!(CustomQuiz::validate_parts(&[part(5), part(10)]), Ok(()));
+        assert_eq!(
+            CustomQuiz::validate_parts(&[part(MAX_CUSTOM_QUIZ_QUESTIONS)]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_questions() {
+        assert!(CustomQuiz::validate_parts(&[part(MAX_CUSTOM_QUIZ_QUESTIONS + 1)]).is_err());
+        assert!(CustomQuiz::validate_parts(&[part(MAX_CUSTOM_QUIZ_QUESTIONS), part(1)]).is_err());
+        // Would wrap around to 1 if the counts were summed unchecked
+        assert!(CustomQuiz::validate_parts(&[part(usize::MAX), part(2)]).is_err());
+        assert!(CustomQuiz::validate_parts(&[part(0)]).is_err());
+    }
+
+    #[test]
+    fn stored_counts_are_capped_when_generating() {
+        let custom_quiz = CustomQuiz {
+            code: "K7XQ2M".to_string(),
+            title: "Prov".to_string(),
+            difficulty: Difficulty::Normal,
+            seed: 1,
+            parts: vec![part(usize::MAX), part(2)],
+        };
+        assert_eq!(custom_quiz.number_of_questions(), usize::MAX);
+        assert_eq!(
+            custom_quiz.generate_questions(1).len(),
+            MAX_CUSTOM_QUIZ_QUESTIONS
+        );
+    }
+}
@@ -0,0 +1,8 @@
+// A flat figure for the frontend to draw, given by its corners in order with y
+// growing downwards as in SVG. Side i runs from corner i to the next corner, and
+// the last side closes the figure. Sides with a label get it written next to them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Figure {
+    pub corners: Vec<(f64, f64)>,
+    pub side_labels: Vec<Option<String>>,
+}
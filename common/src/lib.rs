@@ -1,5 +1,14 @@
+pub mod answer;
 pub mod config;
+pub mod custom_quiz;
+pub mod figure;
+pub mod number;
+pub mod polynomial;
+pub mod quiz;
+pub mod quizzes;
 use chrono::{DateTime, Utc};
+use custom_quiz::CustomQuizPart;
+use quiz::Difficulty;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -9,6 +18,7 @@ pub struct LeaderboardEntry {
     pub course: String,
     pub school: String,
     pub school_id: uuid::Uuid,
+    pub student_id: uuid::Uuid,
     pub time_seconds: f64,
     pub completed_at: Option<DateTime<Utc>>, // This is still optional as default value is handled in the database
 }
@@ -16,6 +26,8 @@ pub struct LeaderboardEntry {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaderboardRequest {
     pub course: String,
+    #[serde(default)] // Leaderboards are kept per difficulty
+    pub difficulty: Difficulty,
     pub school: String,
     pub school_id: uuid::Uuid,
     pub limit: Option<usize>,
@@ -27,7 +39,45 @@ pub struct SubmitScoreRequest {
     pub course: String,
     pub school: String,
     pub school_id: uuid::Uuid,
-    pub time_seconds: f64,
+    pub student_id: uuid::Uuid,
+    pub session_id: uuid::Uuid, // The time is taken from this finished session
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartSessionRequest {
+    pub course: String,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    pub school_id: uuid::Uuid,
+    pub student_id: uuid::Uuid,
+}
+
+// Sent by a teacher to store a custom quiz. The server picks a seed when none is given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomQuizRequest {
+    pub title: String,
+    pub difficulty: Difficulty,
+    pub seed: Option<u64>,
+    pub school_id: uuid::Uuid,
+    pub parts: Vec<CustomQuizPart>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StartSessionResponse {
+    pub session_id: uuid::Uuid,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishSessionRequest {
+    pub session_id: uuid::Uuid,
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FinishSessionResponse {
+    pub time_seconds: f64, // Measured by the server between start and finish
+    pub passed: bool,      // Every answer was correct for the session's questions
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -35,17 +85,31 @@ pub struct User {
     pub name: String,
     pub school: String,
     pub school_id: uuid::Uuid,
+    pub student_id: uuid::Uuid, // One per student name in a browser, keys the student's own leaderboard entries
 }
 
 impl User {
-    pub fn new_dummy() -> Self {
+    pub fn for_school(school: &School, name: &str, student_id: uuid::Uuid) -> Self {
         Self {
-            name: "Dummy".to_string(),
-            school: "Donald Duck University".to_string(),
-            school_id: uuid::Uuid::new_v4(),
+            name: name.to_string(),
+            school: school.name.clone(),
+            school_id: school.school_id,
+            student_id,
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct School {
+    pub school_id: uuid::Uuid,
+    pub name: String,
+    pub paid_until: DateTime<Utc>, // Submissions are rejected after this date
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchoolAccessRequest {
+    pub access_code: String,
+}
 #[cfg_attr(feature = "backend", derive(sqlx::FromRow))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct TopUserSchoolEntry {
@@ -0,0 +1,158 @@
+// Reading numbers the way Swedish students type them: decimal comma or point
+// ("2,5", "2.5"), spaces between thousands ("1 250"), fractions ("3/4") and
+// mixed numbers ("1 1/2").
+
+// Parses any numeric answer to its value
+pub fn parse_number(input: &str) -> Option<f64> {
+    if let Some((numerator, denominator)) = parse_rational(input) {
+        return Some(numerator as f64 / denominator as f64);
+    }
+    // Decimals with too many digits for an exact fraction
+    let (negative, rest) = split_sign(input.trim());
+    let (integer, decimals) = split_decimals(rest)?;
+    let value: f64 = format!("{}.{}", join_thousands(integer)?, decimals)
+        .parse()
+        .ok()?;
+    Some(if negative { -value } else { value })
+}
+
+// Parses a numeric answer to an exact fraction (numerator, denominator).
+// The fraction is not reduced and the denominator is always positive.
+pub fn parse_rational(input: &str) -> Option<(i64, i64)> {
+    let (negative, rest) = split_sign(input.trim());
+
+    let (numerator, denominator) = match rest.split_once('/') {
+        Some((whole_and_numerator, denominator)) => {
+            let denominator = parse_digits(denominator.trim())?;
+            // A mixed number has its whole part before the last space
+            let (whole, numerator) = match whole_and_numerator
+                .trim()
+                .rsplit_once(|c: char| c.is_whitespace())
+            {
+                Some((whole, numerator)) => (
+                    join_thousands(whole.trim())?.parse::<i64>().ok()?,
+                    parse_digits(numerator)?,
+                ),
+                None => (0, parse_digits(whole_and_numerator.trim())?),
+            };
+            (
+                whole.checked_mul(denominator)?.checked_add(numerator)?,
+                denominator,
+            )
+        }
+        None => {
+            let (integer, decimals) = split_decimals(rest)?;
+            let integer = join_thousands(integer)?;
+            let denominator = 10_i64.checked_pow(decimals.len() as u32)?;
+            let numerator = format!("{}{}", integer, decimals).parse::<i64>().ok()?;
+            (numerator, denominator)
+        }
+    };
+
+    if denominator == 0 {
+        return None;
+    }
+    Some((if negative { -numerator } else { numerator }, denominator))
+}
+
+fn split_sign(input: &str) -> (bool, &str) {
+    if let Some(rest) = input.strip_prefix(['-', '−']) {
+        (true, rest.trim_start())
+    } else if let Some(rest) = input.strip_prefix('+') {
+        (false, rest.trim_start())
+    } else {
+        (false, input)
+    }
+}
+
+// Splits "1 250,75" into "1 250" and "75". Either a comma or a point may be used.
+fn split_decimals(input: &str) -> Option<(&str, &str)> {
+    let (integer, decimals) = match input.split_once([',', '.']) {
+        Some((integer, decimals)) => (integer, decimals),
+        None => (input, ""),
+    };
+    if !decimals.chars().all(|c| c.is_ascii_digit()) || (integer.is_empty() && decimals.is_empty())
+    {
+        return None;
+    }
+    // ",5" is read as 0,5
+    Some((if integer.is_empty() { "0" } else { integer }, decimals))
+}
+
+// Removes spaces between groups of thousands. Only proper groupings are
+// accepted, so "1 250" is 1250 but "12 50" is not a number.
+fn join_thousands(input: &str) -> Option<String> {
+    let groups: Vec<&str> = input.split(|c: char| c.is_whitespace()).collect();
+    let digits_only = groups
+        .iter()
+        .all(|group| !group.is_empty() && group.chars().all(|c| c.is_ascii_digit()));
+    let grouped = groups.len() == 1
+        || (groups[0].len() <= 3 && groups[1..].iter().all(|group| group.len() == 3));
+    (digits_only && grouped).then(|| groups.concat())
+}
+
+fn parse_digits(input: &str) -> Option<i64> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    input.parse().ok()
+}
+
+// Writes a computed value for explanations, with at most four decimals. Values
+// below 0,1 get more, so that they keep four significant figures.
+pub fn format_number(value: f64) -> String {
+    let decimals = if value == 0.0 {
+        4
+    } else {
+        (3 - value.abs().log10().floor() as i32).clamp(4, 15)
+    };
+    let factor = 10_f64.powi(decimals);
+    let rounded = (value * factor).round() / factor;
+    // Avoid "-0" for tiny negative values
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_swedish_number_forms() {
+        assert_eq!(parse_number("2,5"), Some(2.5));
+        assert_eq!(parse_number("2.5"), Some(2.5));
+        assert_eq!(parse_number("3/4"), Some(0.75));
+        assert_eq!(parse_number("1 1/2"), Some(1.5));
+        assert_eq!(parse_number("1 250"), Some(1250.0));
+        assert_eq!(parse_number("−1 250,5"), Some(-1250.5));
+        assert_eq!(parse_number(",5"), Some(0.5));
+    }
+
+    #[test]
+    fn formats_small_values_with_four_significant_figures() {
+        assert_eq!(format_number(12.34567), "12.3457");
+        assert_eq!(format_number(0.0031416), "0.003142");
+        assert_eq!(format_number(-0.00001), "-0.00001");
+        assert_eq!(format_number(0.00000001), "0.00000001");
+    }
+
+    #[test]
+    fn keeps_fractions_exact() {
+        assert_eq!(parse_rational("3/4"), Some((3, 4)));
+        assert_eq!(parse_rational("1 1/2"), Some((3, 2)));
+        assert_eq!(parse_rational("-2 1/3"), Some((-7, 3)));
+        assert_eq!(parse_rational("2,50"), Some((250, 100)));
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        assert_eq!(parse_number("12 50"), None);
+        assert_eq!(parse_number("3/0"), None);
+        assert_eq!(parse_number("2,5,1"), None);
+        assert_eq!(parse_number(""), None);
+        assert_eq!(parse_number("abc"), None);
+    }
+}
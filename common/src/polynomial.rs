@@ -0,0 +1,309 @@
+use std::collections::BTreeMap;
+
+// Highest total degree of a term, and most terms in an expansion, so answers like
+// "x^999999" or "(a+b+c+d+e+f+g+h)^20" are rejected instead of expanded
+const MAX_DEGREE: u32 = 20;
+const MAX_TERMS: usize = 200;
+
+// Coefficients closer than this are treated as equal
+const EPSILON: f64 = 1e-9;
+
+// A product of variables with their powers, e.g. x^2*y is [('x', 2), ('y', 1)].
+// The empty monomial is the constant term.
+type Monomial = Vec<(char, u32)>;
+
+// A polynomial in any number of single-letter variables, expanded to a sum of
+// monomials so that equivalent expressions compare equal: "2 + 6x", "6*x+2"
+// and "6x^1+2" all normalize to the same terms.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial {
+    terms: BTreeMap<Monomial, f64>,
+}
+
+impl Polynomial {
+    // Parses expressions built from numbers, letters, + - * / ^ and parentheses.
+    // Multiplication may be implicit ("6x", "2(x + 1)", "xy"), and division is
+    // only allowed by constants. Returns None when the input can't be read.
+    pub fn parse(input: &str) -> Option<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let polynomial = parser.expression()?;
+        if parser.pos != parser.tokens.len() {
+            return None; // Something was left over, like an unmatched ')'
+        }
+        Some(polynomial)
+    }
+
+    pub fn equivalent(&self, other: &Polynomial) -> bool {
+        self.sub(other).terms.is_empty()
+    }
+
+    fn constant(value: f64) -> Self {
+        Self::from_terms([(Vec::new(), value)])
+    }
+
+    fn variable(name: char) -> Self {
+        Self::from_terms([(vec![(name, 1)], 1.0)])
+    }
+
+    // Sums up like terms and drops those that cancel out
+    fn from_terms(terms: impl IntoIterator<Item = (Monomial, f64)>) -> Self {
+        let mut summed: BTreeMap<Monomial, f64> = BTreeMap::new();
+        for (monomial, coefficient) in terms {
+            *summed.entry(monomial).or_default() += coefficient;
+        }
+        summed.retain(|_, coefficient| coefficient.abs() > EPSILON);
+        Self { terms: summed }
+    }
+
+    fn as_constant(&self) -> Option<f64> {
+        match self.terms.len() {
+            0 => Some(0.0),
+            1 => self.terms.get(&Vec::new()).copied(),
+            _ => None,
+        }
+    }
+
+    fn add(&self, other: &Polynomial) -> Self {
+        Self::from_terms(self.terms.clone().into_iter().chain(other.terms.clone()))
+    }
+
+    fn sub(&self, other: &Polynomial) -> Self {
+        self.add(&other.scale(-1.0))
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Self::from_terms(
+            self.terms
+                .iter()
+                .map(|(monomial, coefficient)| (monomial.clone(), coefficient * factor)),
+        )
+    }
+
+    fn mul(&self, other: &Polynomial) -> Option<Self> {
+        let mut terms = Vec::new();
+        for (left, left_coefficient) in &self.terms {
+            for (right, right_coefficient) in &other.terms {
+                terms.push((
+                    multiply_monomials(left, right)?,
+                    left_coefficient * right_coefficient,
+                ));
+            }
+        }
+        let product = Self::from_terms(terms);
+        (product.terms.len() <= MAX_TERMS).then_some(product)
+    }
+
+    fn pow(&self, exponent: u32) -> Option<Self> {
+        let mut result = Self::constant(1.0);
+        for _ in 0..exponent {
+            result = result.mul(self)?;
+        }
+        Some(result)
+    }
+}
+
+fn multiply_monomials(left: &Monomial, right: &Monomial) -> Option<Monomial> {
+    let mut powers: BTreeMap<char, u32> = left.iter().copied().collect();
+    for &(name, power) in right {
+        *powers.entry(name).or_default() += power;
+    }
+    if powers.values().sum::<u32>() > MAX_DEGREE {
+        return None;
+    }
+    Some(powers.into_iter().collect())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Token {
+    Number(f64),
+    Variable(char),
+    Plus,
+    Minus,
+    Times,
+    Divide,
+    Power,
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '0'..='9' | '.' => {
+                let mut number = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    number.push(next);
+                    chars.next();
+                }
+                Token::Number(number.parse().ok()?)
+            }
+            c if c.is_alphabetic() => Token::Variable(c.to_lowercase().next()?),
+            '+' => Token::Plus,
+            '-' | '−' => Token::Minus,
+            '*' | '·' | '×' => Token::Times,
+            '/' => Token::Divide,
+            '^' => Token::Power,
+            // Superscripts are shorthand for ^2 and ^3
+            '²' | '³' => {
+                tokens.push(Token::Power);
+                Token::Number(if c == '²' { 2.0 } else { 3.0 })
+            }
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => return None,
+        };
+        tokens.push(token);
+    }
+
+    Some(tokens)
+}
+
+// Recursive descent over the grammar
+//   expression = term (("+" | "-") term)*
+//   term       = unary (("*" | "/" | implicit) unary)*
+//   unary      = ("+" | "-") unary | power
+//   power      = atom ("^" number)?
+//   atom       = number | variable | "(" expression ")"
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek()?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn expression(&mut self) -> Option<Polynomial> {
+        let mut result = self.term()?;
+        while let Some(token @ (Token::Plus | Token::Minus)) = self.peek() {
+            self.pos += 1;
+            let right = self.term()?;
+            result = if token == Token::Plus {
+                result.add(&right)
+            } else {
+                result.sub(&right)
+            };
+        }
+        Some(result)
+    }
+
+    fn term(&mut self) -> Option<Polynomial> {
+        let mut result = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::Times) => {
+                    self.pos += 1;
+                    result = result.mul(&self.unary()?)?;
+                }
+                Some(Token::Divide) => {
+                    self.pos += 1;
+                    let divisor = self.unary()?.as_constant()?;
+                    if divisor.abs() < EPSILON {
+                        return None;
+                    }
+                    result = result.scale(1.0 / divisor);
+                }
+                // Implicit multiplication, as in "6x" or "2(x + 1)". A number can't
+                // follow implicitly, so "x6" is rejected rather than read as 6x,
+                // since it is more likely a mistyped x^6.
+                Some(Token::Variable(_) | Token::Open) => {
+                    result = result.mul(&self.power()?)?;
+                }
+                _ => return Some(result),
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Option<Polynomial> {
+        match self.peek()? {
+            Token::Plus => {
+                self.pos += 1;
+                self.unary()
+            }
+            Token::Minus => {
+                self.pos += 1;
+                Some(self.unary()?.scale(-1.0))
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Option<Polynomial> {
+        let base = self.atom()?;
+        if self.peek() != Some(Token::Power) {
+            return Some(base);
+        }
+        self.pos += 1;
+        match self.next()? {
+            Token::Number(exponent)
+                if exponent.fract() == 0.0 && (0.0..=MAX_DEGREE as f64).contains(&exponent) =>
+            {
+                base.pow(exponent as u32)
+            }
+            _ => None,
+        }
+    }
+
+    fn atom(&mut self) -> Option<Polynomial> {
+        match self.next()? {
+            Token::Number(value) => Some(Polynomial::constant(value)),
+            Token::Variable(name) => Some(Polynomial::variable(name)),
+            Token::Open => {
+                let inner = self.expression()?;
+                (self.next()? == Token::Close).then_some(inner)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equivalent(a: &str, b: &str) -> bool {
+        Polynomial::parse(a)
+            .zip(Polynomial::parse(b))
+            .is_some_and(|(a, b)| a.equivalent(&b))
+    }
+
+    #[test]
+    fn equivalent_forms_compare_equal() {
+        assert!(equivalent("2 + 6x", "6*x+2"));
+        assert!(equivalent("2 + 6x", "6x^1+2"));
+        assert!(equivalent("(x + 1)²", "x^2 + 2x + 1"));
+        assert!(equivalent("2(x + y) / 4", "0.5x + 0.5y"));
+        assert!(!equivalent("2 + 6x", "2 + 6x^2"));
+    }
+
+    #[test]
+    fn number_after_variable_is_rejected() {
+        assert_eq!(Polynomial::parse("x6"), None);
+        assert_eq!(Polynomial::parse("2 3"), None);
+        assert!(Polynomial::parse("6x").is_some());
+    }
+
+    #[test]
+    fn large_expansions_are_rejected() {
+        assert_eq!(Polynomial::parse("x^21"), None);
+        assert_eq!(Polynomial::parse("x^10 * y^11"), None);
+        assert_eq!(Polynomial::parse("(a+b+c+d+e+f+g+h)^20"), None);
+        assert_eq!(Polynomial::parse("(a+b+c+d+e+f+g+h+i+j)^20"), None);
+        assert!(Polynomial::parse("(x + 1)^20").is_some());
+    }
+}
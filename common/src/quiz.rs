@@ -0,0 +1,681 @@
+use crate::{answer::Answer, figure::Figure, quizzes::*};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[derive(Clone, PartialEq, Debug, Copy, Eq, Hash, Ord, PartialOrd)]
+pub enum Subject {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+    Number,
+    Geometry,
+    Statisitics,
+    Algebra,
+    Time,
+    Random,
+}
+
+// How hard the generated questions are. Each quiz picks its own number ranges
+// per level, and the leaderboards are kept separate per level.
+#[derive(
+    Clone, PartialEq, Debug, Copy, Eq, Hash, Ord, PartialOrd, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    // The name stored with leaderboard entries and quiz sessions
+    pub fn id(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Difficulty> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.id() == id.trim().to_lowercase())
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "Lätt"),
+            Difficulty::Normal => write!(f, "Normal"),
+            Difficulty::Hard => write!(f, "Svår"),
+        }
+    }
+}
+
+pub trait Question {
+    fn prompt(&self) -> String;
+    fn answer(&self) -> Answer;
+    // Worked steps from the question to the answer, shown when the student got it wrong
+    fn explanation(&self) -> Vec<String>;
+    fn check_answer(&self, answer: &str) -> bool {
+        self.answer().check(answer)
+    }
+
+    // Helper method to display the question nicely, with the precision the answer needs
+    fn display(&self) -> String {
+        match self.answer().instruction() {
+            Some(instruction) => format!("{} {}", self.prompt(), instruction),
+            None => self.prompt(),
+        }
+    }
+
+    // How the frontend should show the question. Most questions are answered in a text field.
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Text
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuestionView<'a> {
+    Text,
+    Choices(Vec<Choice>),
+    // Any number of the choices can be picked before answering
+    MultipleChoices(Vec<Choice>),
+    Clock(&'a ClockReadingQuestion),
+    // The student sets the hands of a clock, answered as the time they show
+    SetClock(&'a ClockReadingQuestion),
+    // Clock faces with a caption each, e.g. when a trip starts and ends, answered
+    // in a text field
+    Clocks(Vec<(&'static str, ClockReadingQuestion)>),
+    // Times of day in a table, one row per caption, answered in a text field
+    Timetable(Vec<(&'static str, String)>),
+    // A drawing the student reads the measurements from, answered in a text field
+    Figure(Figure),
+    // A drawing of a solid with its measurements, answered in a text field
+    Solid(&'a SolidQuestion),
+}
+
+// Registers every quiz in one place. Each entry gives the quiz its title, subject,
+// number of questions, question type and generator, and the macro builds the
+// `Quiz` and `QuestionBox` enums, their dispatch and `ALL_COURSES` from it.
+// Generators are called with the rng and the chosen `Difficulty`.
+// A question type picks its renderer by overriding `Question::view`.
+//
+// Mixed quizzes are listed under `mixes`. They have no question type of their
+// own, but draw each question from one of the quizzes in their `Mix`. They are
+// shown under "Blandat" and get leaderboards of their own, like any other quiz.
+macro_rules! define_quizzes {
+    (
+        quizzes {$(
+            $quiz:ident {
+                title: $title:literal,
+                subject: $subject:ident,
+                questions: $count:literal,
+                question: $question:ty = $generate:path,
+            }
+        )*}
+        mixes {$(
+            $mixed:ident {
+                title: $mixed_title:literal,
+                questions: $mixed_count:literal,
+                mix: $mix:expr,
+            }
+        )*}
+    ) => {
+        #[derive(Clone, PartialEq, Debug, Copy, Eq, Hash)]
+        pub enum Quiz {
+            NoCourse,
+            $($quiz,)*
+            $($mixed,)*
+        }
+
+        // Every course in the order they are listed, starting with the empty choice
+        pub const ALL_COURSES: &[Quiz] = &[Quiz::NoCourse, $(Quiz::$quiz,)* $(Quiz::$mixed,)*];
+
+        impl Display for Quiz {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    Quiz::NoCourse => write!(f, "Välj en quiz"),
+                    $(Quiz::$quiz => write!(f, $title),)*
+                    $(Quiz::$mixed => write!(f, $mixed_title),)*
+                }
+            }
+        }
+
+        impl Quiz {
+            pub fn subject(&self) -> Subject {
+                match self {
+                    Quiz::NoCourse => Subject::Addition,
+                    $(Quiz::$quiz => Subject::$subject,)*
+                    $(Quiz::$mixed => Subject::Random,)*
+                }
+            }
+
+            pub fn number_of_questions(&self) -> usize {
+                match self {
+                    Quiz::NoCourse => 0,
+                    $(Quiz::$quiz => $count,)*
+                    $(Quiz::$mixed => $mixed_count,)*
+                }
+            }
+
+            // The quizzes a mixed quiz draws its questions from
+            pub fn mix(&self) -> Option<Mix> {
+                match self {
+                    $(Quiz::$mixed => Some($mix),)*
+                    _ => None,
+                }
+            }
+
+            pub fn generate_question(
+                &self,
+                rng: &mut impl Rng,
+                difficulty: Difficulty,
+            ) -> Option<QuestionBox> {
+                match self {
+                    Quiz::NoCourse => None,
+                    $(Quiz::$quiz => Some(QuestionBox::$quiz($generate(rng, difficulty))),)*
+                    $(Quiz::$mixed => $mix.pick(rng)?.generate_question(rng, difficulty),)*
+                }
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum QuestionBox {
+            $($quiz($question),)*
+        }
+
+        impl Question for QuestionBox {
+            fn prompt(&self) -> String {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.prompt(),)*
+                }
+            }
+
+            fn answer(&self) -> Answer {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.answer(),)*
+                }
+            }
+
+            fn explanation(&self) -> Vec<String> {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.explanation(),)*
+                }
+            }
+
+            fn check_answer(&self, answer: &str) -> bool {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.check_answer(answer),)*
+                }
+            }
+
+            fn display(&self) -> String {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.display(),)*
+                }
+            }
+
+            fn view(&self) -> QuestionView<'_> {
+                match self {
+                    $(QuestionBox::$quiz(q) => q.view(),)*
+                }
+            }
+        }
+    };
+}
+
+define_quizzes! {
+    quizzes {
+        Addition1_10 {
+            title: "Addition 1-10",
+            subject: Addition,
+            questions: 10,
+            question: AdditionQuestion1_10 = AdditionQuestion1_10::random,
+        }
+        Addition100 {
+            title: "Addition 100-999",
+            subject: Addition,
+            questions: 10,
+            question: AdditionQuestion100 = AdditionQuestion100::random,
+        }
+        Subtraction1_10 {
+            title: "Subtraktion 1-10",
+            subject: Subtraction,
+            questions: 10,
+            question: SubtractionQuestion1_10 = SubtractionQuestion1_10::random,
+        }
+        Multiplication1_10 {
+            title: "Multiplikation 1-10",
+            subject: Multiplication,
+            questions: 10,
+            question: MultiplicationQuestion1_10 = MultiplicationQuestion1_10::random,
+        }
+        Multiplication1_20 {
+            title: "Multiplikation 1-20",
+            subject: Multiplication,
+            questions: 10,
+            question: MultiplicationQuestion1_20 = MultiplicationQuestion1_20::random,
+        }
+        Division1_10 {
+            title: "Division 1-10",
+            subject: Division,
+            questions: 10,
+            question: DivisionQuestion1_10 = DivisionQuestion1_10::random,
+        }
+        MissingOperator {
+            title: "Vilket räknesätt?",
+            subject: Number,
+            questions: 10,
+            question: MissingOperatorQuestion = MissingOperatorQuestion::random,
+        }
+        Area {
+            title: "Area",
+            subject: Geometry,
+            questions: 10,
+            question: AreaQuestion = AreaQuestion::random,
+        }
+        Circumference {
+            title: "Omkrets",
+            subject: Geometry,
+            questions: 10,
+            question: CircumferenceQuestion = CircumferenceQuestion::random,
+        }
+        Perimeter {
+            title: "Omkrets av figurer",
+            subject: Geometry,
+            questions: 10,
+            question: PerimeterQuestion = PerimeterQuestion::random,
+        }
+        Solids {
+            title: "Volym och begränsningsarea",
+            subject: Geometry,
+            questions: 10,
+            question: SolidQuestion = SolidQuestion::random,
+        }
+        FirstOrderEquation {
+            title: "Första ordningens ekvation",
+            subject: Algebra,
+            questions: 10,
+            question: FirstOrderEquationQuestion = FirstOrderEquationQuestion::random,
+        }
+        FirstDegreeDerivativeQuestion {
+            title: "Första gradens derivata",
+            subject: Algebra,
+            questions: 10,
+            question: FirstDegreeDerivativeQuestion = FirstDegreeDerivativeQuestion::random,
+        }
+        NumberComparison {
+            title: "Störst värde",
+            subject: Number,
+            questions: 10,
+            question: NumberComparisonQuestion = NumberComparisonQuestion::random,
+        }
+        FractionComparison {
+            title: "Störst bråk",
+            subject: Number,
+            questions: 10,
+            question: FractionComparisonQuestion = FractionComparisonQuestion::random,
+        }
+        FractionArithmetic {
+            title: "Räkna med bråk",
+            subject: Number,
+            questions: 10,
+            question: FractionArithmeticQuestion = FractionArithmeticQuestion::random,
+        }
+        Rounding {
+            title: "Avrundning",
+            subject: Number,
+            questions: 10,
+            question: RoundingQuestion = RoundingQuestion::random,
+        }
+        Average {
+            title: "Medelvärde",
+            subject: Statisitics,
+            questions: 10,
+            question: AverageQuestion = AverageQuestion::random,
+        }
+        Median {
+            title: "Median",
+            subject: Statisitics,
+            questions: 10,
+            question: MedianQuestion = MedianQuestion::random,
+        }
+        FractionToDegree {
+            title: "Bråk till grader",
+            subject: Geometry,
+            questions: 10,
+            question: FractionToDegree = FractionToDegree::random,
+        }
+        PercentChange {
+            title: "Procentuell förändring",
+            subject: Statisitics,
+            questions: 10,
+            question: PercentChangeQuestion = PercentChangeQuestion::random,
+        }
+        Expression {
+            title: "Matematiska uttryck",
+            subject: Algebra,
+            questions: 10,
+            question: ExpressionQuestion = ExpressionQuestion::random,
+        }
+        OrderOfOperations {
+            title: "Prioriteringsregler",
+            subject: Algebra,
+            questions: 10,
+            question: OrderOfOperationsQuestion = OrderOfOperationsQuestion::random,
+        }
+        NegativeValues {
+            title: "Negativa tal",
+            subject: Number,
+            questions: 10,
+            question: NegativeValuesQuestion = NegativeValuesQuestion::random,
+        }
+        ClockReading {
+            title: "Analoga klockan",
+            subject: Time,
+            questions: 10,
+            question: ClockReadingQuestion = ClockReadingQuestion::random_five_minute_intervals,
+        }
+        ClockReadingSimple {
+            title: "Analoga klockan (halvtimmar)",
+            subject: Time,
+            questions: 10,
+            question: ClockReadingQuestion = ClockReadingQuestion::random_simple,
+        }
+        ClockPhrases {
+            title: "Klockan i ord",
+            subject: Time,
+            questions: 10,
+            question: ClockReadingQuestion = ClockReadingQuestion::random_phrase,
+        }
+        Durations {
+            title: "Hur lång tid?",
+            subject: Time,
+            questions: 10,
+            question: DurationQuestion = DurationQuestion::random,
+        }
+        ClockSetting {
+            title: "Ställ klockan",
+            subject: Time,
+            questions: 10,
+            question: ClockReadingQuestion = ClockReadingQuestion::random_setting,
+        }
+        RomanNumerals {
+            title: "Romerska siffror",
+            subject: Number,
+            questions: 10,
+            question: RomanNumeralsQuestion = RomanNumeralsQuestion::random,
+        }
+        RomanNumeralsWriting {
+            title: "Skriv romerska siffror",
+            subject: Number,
+            questions: 10,
+            question: RomanNumeralsQuestion = RomanNumeralsQuestion::random_writing,
+        }
+        Divisibility {
+            title: "Delbarhet",
+            subject: Number,
+            questions: 10,
+            question: DivisibilityQuestion = DivisibilityQuestion::random,
+        }
+    }
+    mixes {
+        MixedNumber {
+            title: "Blandat: tal",
+            questions: 10,
+            mix: Mix::Subject(Subject::Number),
+        }
+        MixedArithmetic {
+            title: "Blandat: räknesätt",
+            questions: 12,
+            mix: Mix::Weighted(&[
+                (Quiz::Addition1_10, 1),
+                (Quiz::Addition100, 1),
+                (Quiz::Subtraction1_10, 2),
+                (Quiz::Multiplication1_10, 2),
+                (Quiz::Multiplication1_20, 1),
+                (Quiz::Division1_10, 2),
+            ]),
+        }
+        MixedAll {
+            title: "Blandat: allt",
+            questions: 15,
+            mix: Mix::Weighted(&[
+                (Quiz::Addition100, 2),
+                (Quiz::Multiplication1_10, 2),
+                (Quiz::Division1_10, 2),
+                (Quiz::NegativeValues, 2),
+                (Quiz::NumberComparison, 1),
+                (Quiz::FractionComparison, 1),
+                (Quiz::Rounding, 1),
+                (Quiz::Area, 1),
+                (Quiz::Circumference, 1),
+                (Quiz::Average, 1),
+                (Quiz::Median, 1),
+                (Quiz::PercentChange, 1),
+                (Quiz::FirstOrderEquation, 1),
+                (Quiz::ClockReading, 1),
+                (Quiz::RomanNumerals, 1),
+            ]),
+        }
+    }
+}
+
+// Where a mixed quiz takes its questions from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mix {
+    // Every quiz in the subject, equally often
+    Subject(Subject),
+    // The listed quizzes, each drawn in proportion to its weight
+    Weighted(&'static [(Quiz, u32)]),
+}
+
+impl Mix {
+    pub fn quizzes(&self) -> Vec<(Quiz, u32)> {
+        match self {
+            Mix::Subject(subject) => ALL_COURSES
+                .iter()
+                .filter(|quiz| quiz.mix().is_none() && quiz.subject() == *subject)
+                .map(|quiz| (*quiz, 1))
+                .collect(),
+            Mix::Weighted(quizzes) => quizzes.to_vec(),
+        }
+    }
+
+    // Picks the quiz the next question comes from
+    fn pick(&self, rng: &mut impl Rng) -> Option<Quiz> {
+        let quizzes = self.quizzes();
+        let total: u32 = quizzes.iter().map(|(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0..total);
+        for (quiz, weight) in quizzes {
+            if roll < weight {
+                return Some(quiz);
+            }
+            roll -= weight;
+        }
+        None
+    }
+}
+
+impl Quiz {
+    // The course name stored with leaderboard entries and quiz sessions
+    pub fn course_id(&self) -> String {
+        self.to_string().to_lowercase()
+    }
+
+    pub fn from_course_id(course: &str) -> Option<Quiz> {
+        let course = course.trim().to_lowercase();
+        ALL_COURSES
+            .iter()
+            .copied()
+            .find(|quiz| *quiz != Quiz::NoCourse && quiz.course_id() == course)
+    }
+}
+
+// Function to generate questions based on quiz type.
+// The same quiz, count, seed and difficulty always give the same questions, which
+// lets the backend regenerate a session's questions to grade the submitted answers.
+pub fn generate_questions(
+    quiz_type: Quiz,
+    count: usize,
+    seed: u64,
+    difficulty: Difficulty,
+) -> Vec<QuestionBox> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..count)
+        .filter_map(|_| quiz_type.generate_question(&mut rng, difficulty))
+        .collect()
+}
+
+// A run is passed when every question got a correct answer, in order
+pub fn all_answers_correct(questions: &[QuestionBox], answers: &[String]) -> bool {
+    questions.len() == answers.len()
+        && questions
+            .iter()
+            .zip(answers)
+            .all(|(question, answer)| question.check_answer(answer))
+}
+
+// Seed for quizzes that are played without a session from the backend
+pub fn random_seed() -> u64 {
+    rand::random()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Choice {
+    pub display_text: String,
+    pub value: String, // The value to emit when this choice is selected, and to be checked by `check_answer`
+}
+pub trait MultipleChoiceQuestionProvider: Question + Clone + PartialEq + std::fmt::Debug {
+    fn get_choices(&self) -> Vec<Choice>;
+}
+
+// quizzes/simple_addition_choice_quiz.rs (new file)
+// use crate::answer::Answer;
+// use crate::quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView};
+// use rand::Rng;
+
+// #[derive(Clone, Debug, PartialEq)]
+// pub struct SimpleAdditionChoiceQuestion {
+//     term1: i32,
+//     term2: i32,
+//     choices_values: Vec<i32>, // Values for the buttons
+//     correct_answer_value: i32,
+// }
+
+// impl SimpleAdditionChoiceQuestion {
+//     pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+//         let term1 = 2; // Fixed for "What is 2 + X?"
+//         let max_term = match difficulty {
+//             Difficulty::Easy => 5,
+//             Difficulty::Normal => 10,
+//             Difficulty::Hard => 20,
+//         };
+//         let term2 = rng.random_range(1..=max_term);
+//         let correct_result = term1 + term2;
+
+//         let mut choices_values = vec![correct_result];
+//         // Generate 2 or 3 wrong answers
+//         let num_wrong_answers = rng.random_range(2..=3); // For 3 or 4 total choices
+//         while choices_values.len() < (1 + num_wrong_answers) {
+//             let wrong_offset = rng.random_range(-3..=3);
+//             if wrong_offset == 0 { continue; } // Avoid same as correct or other wrong answers
+//             let wrong_answer = correct_result + wrong_offset;
+//             if !choices_values.contains(&wrong_answer) {
+//                 choices_values.push(wrong_answer);
+//             }
+//         }
+//         use rand::seq::SliceRandom;
+//         choices_values.shuffle(rng); // Shuffle the choices
+
+//         Self {
+//             term1,
+//             term2,
+//             choices_values,
+//             correct_answer_value: correct_result,
+//         }
+//     }
+// }
+
+// impl Question for SimpleAdditionChoiceQuestion {
+//     fn prompt(&self) -> String {
+//         format!("Vad blir {} + {}?", self.term1, self.term2)
+//     }
+
+//     // The choice buttons emit their index, which Answer::Choice checks.
+//     // The label is shown if the user gets it wrong.
+//     fn answer(&self) -> Answer {
+//         let index = self
+//             .choices_values
+//             .iter()
+//             .position(|&val| val == self.correct_answer_value)
+//             .unwrap_or_default();
+//         Answer::Choice {
+//             index,
+//             label: self.correct_answer_value.to_string(),
+//         }
+//     }
+
+//     fn explanation(&self) -> Vec<String> {
+//         vec![format!(
+//             "{} + {} = {}",
+//             self.term1, self.term2, self.correct_answer_value
+//         )]
+//     }
+
+//     fn display(&self) -> String {
+//         self.prompt()
+//     }
+
+//     fn view(&self) -> QuestionView<'_> {
+//         QuestionView::Choices(self.get_choices())
+//     }
+// }
+
+// impl MultipleChoiceQuestionProvider for SimpleAdditionChoiceQuestion {
+//     fn get_choices(&self) -> Vec<Choice> {
+//         self.choices_values
+//             .iter()
+//             .enumerate()
+//             .map(|(index, val)| Choice {
+//                 display_text: val.to_string(),
+//                 value: index.to_string(), // Send the index as a string
+//             })
+//             .collect()
+//     }
+// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_questions() {
+        for &quiz in ALL_COURSES {
+            for difficulty in Difficulty::ALL {
+                for seed in 0..5 {
+                    let first = generate_questions(quiz, 10, seed, difficulty);
+                    let second = generate_questions(quiz, 10, seed, difficulty);
+                    assert_eq!(first.len(), second.len(), "{:?}", quiz);
+                    for (a, b) in first.iter().zip(&second) {
+                        assert_eq!(a.prompt(), b.prompt(), "{:?}", quiz);
+                        assert_eq!(a.answer(), b.answer(), "{:?}", quiz);
+                        assert_eq!(a.view(), b.view(), "{:?}", quiz);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{answer::Answer, quiz::*};
+use rand::Rng;
+
+// Range of the terms per difficulty. Easy sums stay below 1000.
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (100, 500),
+        Difficulty::Normal => (100, 999),
+        Difficulty::Hard => (500, 999),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdditionQuestion100 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl AdditionQuestion100 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result = first + second;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        Self::new(
+            rng.random_range(range.0..range.1),
+            rng.random_range(range.0..range.1),
+        )
+    }
+}
+
+impl Question for AdditionQuestion100 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} + {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        // Add hundreds, tens and ones separately
+        let (first, second) = (self.first_number, self.second_number);
+        let hundreds = (first / 100 * 100, second / 100 * 100);
+        let tens = (first / 10 % 10 * 10, second / 10 % 10 * 10);
+        let ones = (first % 10, second % 10);
+        vec![
+            "Dela upp talen i hundratal, tiotal och ental.".to_string(),
+            format!(
+                "Hundratal: {} + {} = {}",
+                hundreds.0,
+                hundreds.1,
+                hundreds.0 + hundreds.1
+            ),
+            format!("Tiotal: {} + {} = {}", tens.0, tens.1, tens.0 + tens.1),
+            format!("Ental: {} + {} = {}", ones.0, ones.1, ones.0 + ones.1),
+            format!(
+                "Summan: {} + {} + {} = {}",
+                hundreds.0 + hundreds.1,
+                tens.0 + tens.1,
+                ones.0 + ones.1,
+                self.answer
+            ),
+        ]
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{answer::Answer, quiz::*};
+use rand::Rng;
+
+// Range of the terms per difficulty. Hard uses the larger numbers, so the
+// quiz still stays within 1-10.
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 5),
+        Difficulty::Normal => (1, 10),
+        Difficulty::Hard => (5, 10),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdditionQuestion1_10 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl AdditionQuestion1_10 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result = first + second;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        Self::new(
+            rng.random_range(range.0..range.1),
+            rng.random_range(range.0..range.1),
+        )
+    }
+}
+
+impl Question for AdditionQuestion1_10 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} + {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        // Count on from the larger number
+        let larger = self.first_number.max(self.second_number);
+        let smaller = self.first_number.min(self.second_number);
+        let counted: Vec<String> = (larger + 1..=larger + smaller)
+            .map(|number| number.to_string())
+            .collect();
+        vec![
+            format!(
+                "Börja på det större talet {} och räkna {} steg uppåt: {}",
+                larger,
+                smaller,
+                counted.join(", ")
+            ),
+            format!(
+                "{} + {} = {}",
+                self.first_number, self.second_number, self.answer
+            ),
+        ]
+    }
+}
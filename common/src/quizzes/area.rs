@@ -3,7 +3,19 @@ use std::fmt;
 
 use rand::Rng;
 
-use crate::quiz::Question;
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+};
+
+// Upper bound of the side lengths and of the circle radius per difficulty
+fn max_lengths(difficulty: Difficulty) -> (u32, u32) {
+    match difficulty {
+        Difficulty::Easy => (10, 6),
+        Difficulty::Normal => (20, 15),
+        Difficulty::Hard => (50, 30),
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShapeType {
@@ -11,6 +23,8 @@ pub enum ShapeType {
     Rectangle,
     Triangle,
     Circle,
+    RegularPolygon,
+    LShape,
 }
 
 impl fmt::Display for ShapeType {
@@ -20,6 +34,8 @@ impl fmt::Display for ShapeType {
             ShapeType::Rectangle => write!(f, "rektangel"),
             ShapeType::Triangle => write!(f, "triangel"),
             ShapeType::Circle => write!(f, "cirkel"),
+            ShapeType::RegularPolygon => write!(f, "regelbunden månghörning"),
+            ShapeType::LShape => write!(f, "L-form"),
         }
     }
 }
@@ -37,7 +53,7 @@ pub struct AreaQuestion {
     shape_type: ShapeType,
     dimensions: ShapeDimensions,
     unit: String,
-    answer_text: String,
+    answer: Answer,
 }
 
 impl AreaQuestion {
@@ -47,7 +63,7 @@ impl AreaQuestion {
             shape_type: ShapeType::Square,
             dimensions: ShapeDimensions::Square { side },
             unit: unit.to_string(),
-            answer_text: area.to_string(),
+            answer: Answer::Integer(area.into()),
         }
     }
 
@@ -57,33 +73,33 @@ impl AreaQuestion {
             shape_type: ShapeType::Rectangle,
             dimensions: ShapeDimensions::Rectangle { length, width },
             unit: unit.to_string(),
-            answer_text: area.to_string(),
+            answer: Answer::Integer(area.into()),
         }
     }
 
     pub fn new_triangle(base: u32, height: u32, unit: &str) -> Self {
-        // Triangle area = 0.5 * base * height
-        let area = (base * height) / 2;
+        // Triangle area = 0.5 * base * height, which can end in ,5
+        let area = (base * height) as f64 / 2.0;
         Self {
             shape_type: ShapeType::Triangle,
             dimensions: ShapeDimensions::Triangle { base, height },
             unit: unit.to_string(),
-            answer_text: area.to_string(),
+            answer: Answer::decimal(area, Tolerance::Exact),
         }
     }
 
     pub fn new_circle(radius: u32, unit: &str) -> Self {
-        let area = (f64::consts::PI * (radius * radius) as f64).round() as u32;
+        let area = f64::consts::PI * (radius * radius) as f64;
         Self {
             shape_type: ShapeType::Circle,
             dimensions: ShapeDimensions::Circle { radius },
             unit: unit.to_string(),
-            answer_text: area.to_string(),
+            answer: Answer::decimal(area, Tolerance::decimals(0)),
         }
     }
 
-    pub fn random() -> Self {
-        let mut rng = rand::rng();
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (max_side, max_radius) = max_lengths(difficulty);
         let units = ["cm", "m"];
         let unit = units[rng.random_range(0..units.len())];
 
@@ -91,24 +107,24 @@ impl AreaQuestion {
         match rng.random_range(0..4) {
             0 => {
                 // Square
-                let side = rng.random_range(1..20);
+                let side = rng.random_range(1..max_side);
                 Self::new_square(side, unit)
             }
             1 => {
                 // Rectangle
-                let length = rng.random_range(2..20);
+                let length = rng.random_range(2..max_side);
                 let width = rng.random_range(1..length); // Ensure width <= length
                 Self::new_rectangle(length, width, unit)
             }
             2 => {
                 // Triangle
-                let base = rng.random_range(2..20);
-                let height = rng.random_range(2..20);
+                let base = rng.random_range(2..max_side);
+                let height = rng.random_range(2..max_side);
                 Self::new_triangle(base, height, unit)
             }
             _ => {
                 // Circle
-                let radius = rng.random_range(1..15);
+                let radius = rng.random_range(1..max_radius);
                 Self::new_circle(radius, unit)
             }
         }
@@ -138,14 +154,50 @@ impl Question for AreaQuestion {
             }
             ShapeDimensions::Circle { radius } => {
                 format!(
-                    "Beräkna area av en cirkel med radien {}{}? (Närmaste heltal)",
+                    "Beräkna area av en cirkel med radien {}{}?",
                     radius, self.unit
                 )
             }
         }
     }
 
-    fn answer(&self) -> &str {
-        &self.answer_text
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let unit = &self.unit;
+        match &self.dimensions {
+            ShapeDimensions::Square { side } => vec![
+                "Arean av en kvadrat är sidan × sidan.".to_string(),
+                format!("{} × {} = {} {}²", side, side, self.answer, unit),
+            ],
+            ShapeDimensions::Rectangle { length, width } => vec![
+                "Arean av en rektangel är längden × bredden.".to_string(),
+                format!("{} × {} = {} {}²", length, width, self.answer, unit),
+            ],
+            ShapeDimensions::Triangle { base, height } => vec![
+                "Arean av en triangel är basen × höjden / 2.".to_string(),
+                format!(
+                    "{} × {} / 2 = {} / 2 = {} {}²",
+                    base,
+                    height,
+                    base * height,
+                    self.answer,
+                    unit
+                ),
+            ],
+            ShapeDimensions::Circle { radius } => vec![
+                "Arean av en cirkel är π × radien².".to_string(),
+                format!(
+                    "{} {}²",
+                    self.answer.equation(
+                        &format!("π × {}² = π × {}", radius, radius * radius),
+                        f64::consts::PI * (radius * radius) as f64,
+                    ),
+                    unit
+                ),
+            ],
+        }
     }
 }
@@ -0,0 +1,72 @@
+use rand::Rng;
+
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+};
+
+// How many numbers there are, and how large they are, per difficulty
+fn ranges(difficulty: Difficulty) -> ((u32, u32), (i32, i32)) {
+    match difficulty {
+        Difficulty::Easy => ((3, 4), (0, 10)),
+        Difficulty::Normal => ((4, 6), (0, 20)),
+        Difficulty::Hard => ((6, 9), (0, 100)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AverageQuestion {
+    numbers: Vec<i32>,
+    answer: Answer,
+}
+
+impl AverageQuestion {
+    pub fn new(numbers: Vec<i32>) -> Self {
+        let average: f64 = numbers.iter().sum::<i32>() as f64 / numbers.len() as f64;
+        Self {
+            numbers,
+            answer: Answer::decimal(average, Tolerance::decimals(1)),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (numbers_count_range, number_value_range) = ranges(difficulty);
+        loop {
+            let number_count: u32 = rng.random_range(numbers_count_range.0..numbers_count_range.1);
+            let numbers: Vec<i32> = (0..number_count)
+                .map(|_| rng.random_range(number_value_range.0..number_value_range.1))
+                .collect();
+            let sum: i32 = numbers.iter().sum();
+            if number_count > 0 && sum % (number_count as i32) == 0 {
+                let integer_average: i32 = sum / (number_count as i32);
+                return Self {
+                    numbers,
+                    answer: Answer::Integer(integer_average.into()),
+                };
+            }
+        }
+    }
+}
+
+impl Question for AverageQuestion {
+    fn prompt(&self) -> String {
+        format!("Beräkna medelvärdet: {:?}", self.numbers)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let sum: i32 = self.numbers.iter().sum();
+        let terms: Vec<String> = self.numbers.iter().map(|n| n.to_string()).collect();
+        vec![
+            format!("Summera talen: {} = {}", terms.join(" + "), sum),
+            format!("Dela summan med antalet tal, {}.", self.numbers.len()),
+            self.answer.equation(
+                &format!("{} / {}", sum, self.numbers.len()),
+                sum as f64 / self.numbers.len() as f64,
+            ),
+        ]
+    }
+}
@@ -1,7 +1,21 @@
 use core::f64;
 use rand::Rng;
 
-use crate::{quiz::Question, quizzes::ShapeType};
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+    quizzes::ShapeType,
+};
+
+// Upper bounds of the sides of squares and rectangles, of triangle sides and of
+// the circle radius, per difficulty
+fn max_lengths(difficulty: Difficulty) -> (u32, u32, u32) {
+    match difficulty {
+        Difficulty::Easy => (10, 8, 6),
+        Difficulty::Normal => (20, 15, 15),
+        Difficulty::Hard => (100, 50, 50),
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 enum ShapeDimensions {
@@ -27,7 +41,7 @@ pub struct CircumferenceQuestion {
     shape_type: ShapeType,
     dimensions: ShapeDimensions,
     unit: String,
-    answer_text: String,
+    answer: Answer,
 }
 
 impl CircumferenceQuestion {
@@ -37,7 +51,7 @@ impl CircumferenceQuestion {
             shape_type: ShapeType::Square,
             dimensions: ShapeDimensions::Square { side },
             unit: unit.to_string(),
-            answer_text: circumference.to_string(),
+            answer: Answer::Integer(circumference.into()),
         }
     }
 
@@ -47,7 +61,7 @@ impl CircumferenceQuestion {
             shape_type: ShapeType::Rectangle,
             dimensions: ShapeDimensions::Rectangle { length, width },
             unit: unit.to_string(),
-            answer_text: circumference.to_string(),
+            answer: Answer::Integer(circumference.into()),
         }
     }
 
@@ -62,22 +76,22 @@ impl CircumferenceQuestion {
                 side_c,
             },
             unit: unit.to_string(),
-            answer_text: circumference.to_string(),
+            answer: Answer::Integer(circumference.into()),
         }
     }
 
     pub fn new_circle(radius: u32, unit: &str) -> Self {
-        let circumference = (2.0 * f64::consts::PI * radius as f64).round() as u32;
+        let circumference = 2.0 * f64::consts::PI * radius as f64;
         Self {
             shape_type: ShapeType::Circle,
             dimensions: ShapeDimensions::Circle { radius },
             unit: unit.to_string(),
-            answer_text: circumference.to_string(),
+            answer: Answer::decimal(circumference, Tolerance::decimals(0)),
         }
     }
 
-    pub fn random() -> Self {
-        let mut rng = rand::rng();
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (max_side, max_triangle_side, max_radius) = max_lengths(difficulty);
         let units = ["cm", "m"];
         let unit = units[rng.random_range(0..units.len())];
 
@@ -85,25 +99,25 @@ impl CircumferenceQuestion {
         match rng.random_range(0..4) {
             0 => {
                 // Square
-                let side = rng.random_range(1..20);
+                let side = rng.random_range(1..max_side);
                 Self::new_square(side, unit)
             }
             1 => {
                 // Rectangle
-                let length = rng.random_range(2..20);
+                let length = rng.random_range(2..max_side);
                 let width = rng.random_range(1..length); // Ensure width <= length
                 Self::new_rectangle(length, width, unit)
             }
             2 => {
                 // Triangle
-                let side_a = rng.random_range(3..15);
-                let side_b = rng.random_range(3..15);
-                let side_c = rng.random_range(3..15);
+                let side_a = rng.random_range(3..max_triangle_side);
+                let side_b = rng.random_range(3..max_triangle_side);
+                let side_c = rng.random_range(3..max_triangle_side);
                 Self::new_triangle(side_a, side_b, side_c, unit)
             }
             _ => {
                 // Circle
-                let radius = rng.random_range(1..15);
+                let radius = rng.random_range(1..max_radius);
                 Self::new_circle(radius, unit)
             }
         }
@@ -137,14 +151,57 @@ impl Question for CircumferenceQuestion {
             }
             ShapeDimensions::Circle { radius } => {
                 format!(
-                    "Beräkna omkrets av en cirkel med radien {}{}? (Närmaste heltal)",
+                    "Beräkna omkrets av en cirkel med radien {}{}?",
                     radius, self.unit
                 )
             }
         }
     }
 
-    fn answer(&self) -> &str {
-        &self.answer_text
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let unit = &self.unit;
+        match &self.dimensions {
+            ShapeDimensions::Square { side } => vec![
+                "Omkretsen av en kvadrat är 4 × sidan.".to_string(),
+                format!("4 × {} = {} {}", side, self.answer, unit),
+            ],
+            ShapeDimensions::Rectangle { length, width } => vec![
+                "Omkretsen av en rektangel är 2 × (längden + bredden).".to_string(),
+                format!(
+                    "2 × ({} + {}) = 2 × {} = {} {}",
+                    length,
+                    width,
+                    length + width,
+                    self.answer,
+                    unit
+                ),
+            ],
+            ShapeDimensions::Triangle {
+                side_a,
+                side_b,
+                side_c,
+            } => vec![
+                "Omkretsen av en triangel är summan av sidorna.".to_string(),
+                format!(
+                    "{} + {} + {} = {} {}",
+                    side_a, side_b, side_c, self.answer, unit
+                ),
+            ],
+            ShapeDimensions::Circle { radius } => vec![
+                "Omkretsen av en cirkel är 2 × π × radien.".to_string(),
+                format!(
+                    "{} {}",
+                    self.answer.equation(
+                        &format!("2 × π × {}", radius),
+                        2.0 * f64::consts::PI * *radius as f64,
+                    ),
+                    unit
+                ),
+            ],
+        }
     }
 }
@@ -0,0 +1,517 @@
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question, QuestionView},
+};
+use rand::Rng;
+
+// Swedish number words for the hours and the minutes in time phrases
+const NUMBER_WORDS: [&str; 21] = [
+    "noll", "ett", "två", "tre", "fyra", "fem", "sex", "sju", "åtta", "nio", "tio", "elva", "tolv",
+    "tretton", "fjorton", "femton", "sexton", "sjutton", "arton", "nitton", "tjugo",
+];
+
+// The time as it is said, e.g. "fem i halv fyra" for 3:25 or 15:25. Past twenty
+// minutes the time is told from the next hour.
+pub fn time_phrase(hour: u8, minute: u8) -> String {
+    let hour_on_dial = (hour + 11) % 12 + 1;
+    let hour = NUMBER_WORDS[hour_on_dial as usize];
+    let next_hour = NUMBER_WORDS[hour_on_dial as usize % 12 + 1];
+    let minutes = |count: u8| {
+        if count.is_multiple_of(5) {
+            NUMBER_WORDS[count as usize].to_string()
+        } else if count == 1 {
+            "en minut".to_string()
+        } else {
+            format!("{} minuter", NUMBER_WORDS[count as usize])
+        }
+    };
+    match minute {
+        0 => hour.to_string(),
+        15 => format!("kvart över {}", hour),
+        30 => format!("halv {}", next_hour),
+        45 => format!("kvart i {}", next_hour),
+        minute @ 1..=20 => format!("{} över {}", minutes(minute), hour),
+        minute @ 21..=29 => format!("{} i halv {}", minutes(30 - minute), next_hour),
+        minute @ 31..=39 => format!("{} över halv {}", minutes(minute - 30), next_hour),
+        minute => format!("{} i {}", minutes(60 - minute), next_hour),
+    }
+}
+
+// Reads a time said in Swedish, e.g. "kvart över tre", "fem i halv åtta" or
+// "klockan är halv 4 på eftermiddagen". Gives the hour on the dial (1-12), the
+// minute, and whether it is afternoon when the phrase says so.
+pub fn parse_time_phrase(input: &str) -> Option<(u8, u8, Option<bool>)> {
+    let input = input.trim().trim_end_matches(['.', '!']).to_lowercase();
+    let mut words: Vec<&str> = input.split_whitespace().collect();
+    if words.first() == Some(&"klockan") {
+        words.remove(0);
+        if words.first() == Some(&"är") {
+            words.remove(0);
+        }
+    }
+
+    let is_afternoon = match words.last() {
+        Some(&("förmiddag" | "förmiddagen" | "morgonen")) => Some(false),
+        Some(&("eftermiddag" | "eftermiddagen" | "kvällen")) => Some(true),
+        _ => None,
+    };
+    if is_afternoon.is_some() {
+        words.pop();
+        if words.last() == Some(&"på") {
+            words.pop();
+        }
+    }
+
+    // The hour the time is told from comes last
+    let hour = parse_number_word(words.pop()?).filter(|hour| (1..=12).contains(hour))?;
+    let previous_hour = (hour + 10) % 12 + 1;
+    let (hour, minute) = match words.as_slice() {
+        [] => (hour, 0),
+        ["kvart", "över"] => (hour, 15),
+        ["kvart", "i"] => (previous_hour, 45),
+        ["halv"] => (previous_hour, 30),
+        [count @ .., "över", "halv"] => (previous_hour, 30 + parse_minutes(count)?),
+        [count @ .., "i", "halv"] => (previous_hour, 30 - parse_minutes(count)?),
+        [count @ .., "över"] => (hour, parse_minutes(count)?),
+        [count @ .., "i"] => (previous_hour, 60 - parse_minutes(count)?),
+        _ => return None,
+    };
+    Some((hour, minute, is_afternoon))
+}
+
+// A number of minutes before or after a full or half hour, e.g. "fem",
+// "7 minuter" or "en minut"
+fn parse_minutes(words: &[&str]) -> Option<u8> {
+    let count = match words {
+        [count] | [count, "minut" | "minuter"] => parse_number_word(count)?,
+        _ => return None,
+    };
+    (1..30).contains(&count).then_some(count)
+}
+
+// A number in words or digits, e.g. "tre", "3" or "tjugofem"
+fn parse_number_word(word: &str) -> Option<u8> {
+    if let Ok(number) = word.parse::<u8>() {
+        return Some(number);
+    }
+    if word == "en" {
+        return Some(1);
+    }
+    if let Some(number) = NUMBER_WORDS.iter().position(|number| *number == word) {
+        return Some(number as u8);
+    }
+    let ones = word.strip_prefix("tjugo")?;
+    if !ones.chars().all(char::is_alphabetic) {
+        return None;
+    }
+    parse_number_word(ones)
+        .filter(|ones| (1..=9).contains(ones))
+        .map(|ones| 20 + ones)
+}
+
+// What the student does with the time
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ClockTask {
+    // Read the clock face and type the time
+    Read,
+    // Read the clock face and say the time in words
+    ReadAsPhrase,
+    // Set the hands to a time shown as HH:MM
+    SetFromDigital,
+    // Set the hands to a time said in words, e.g. "kvart över tre på eftermiddagen"
+    SetFromPhrase,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockReadingQuestion {
+    hour: u8,           // 0-23 (24-hour format)
+    minute: u8,         // 0-59
+    is_afternoon: bool, // true for eftermiddag (PM), false for förmiddag (AM)
+    task: ClockTask,
+}
+
+impl ClockReadingQuestion {
+    pub fn new(hour: u8, minute: u8, is_afternoon: bool) -> Self {
+        // Convert 12-hour display to 24-hour format
+        let hour_24 = if is_afternoon && hour != 12 {
+            hour + 12
+        } else if !is_afternoon && hour == 12 {
+            // 12 AM (midnight) is 00 hours
+            0
+        } else {
+            hour
+        };
+
+        Self {
+            hour: hour_24,
+            minute,
+            is_afternoon,
+            task: ClockTask::Read,
+        }
+    }
+
+    // Quarters on Easy, five minutes on Normal and any minute on Hard
+    pub fn random_phrase(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let step = match difficulty {
+            Difficulty::Easy => 15,
+            Difficulty::Normal => 5,
+            Difficulty::Hard => 1,
+        };
+        let mut question = Self::random_in_steps(rng, step);
+        question.task = ClockTask::ReadAsPhrase;
+        question
+    }
+
+    // The hands are set to whole hours on Easy, quarters on Normal and five
+    // minutes on Hard, which is also the step the hands snap to
+    pub fn random_setting(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let step = match difficulty {
+            Difficulty::Easy => 60,
+            Difficulty::Normal => 15,
+            Difficulty::Hard => 5,
+        };
+        let mut question = Self::random_in_steps(rng, step);
+        question.task = if rng.random_bool(0.5) {
+            ClockTask::SetFromDigital
+        } else {
+            ClockTask::SetFromPhrase
+        };
+        question
+    }
+
+    // Whole hours on Easy, half hours on Normal and quarters on Hard
+    pub fn random_simple(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let step = match difficulty {
+            Difficulty::Easy => 60,
+            Difficulty::Normal => 30,
+            Difficulty::Hard => 15,
+        };
+        Self::random_in_steps(rng, step)
+    }
+
+    // Half hours on Easy, five minutes on Normal and any minute on Hard
+    pub fn random_five_minute_intervals(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let step = match difficulty {
+            Difficulty::Easy => 30,
+            Difficulty::Normal => 5,
+            Difficulty::Hard => 1,
+        };
+        Self::random_in_steps(rng, step)
+    }
+
+    pub fn random_any_minute(rng: &mut impl Rng) -> Self {
+        Self::random_in_steps(rng, 1)
+    }
+
+    // A random time in the morning or afternoon whose minutes are a multiple of
+    // `step`, e.g. 15 for quarters or 60 for whole hours
+    fn random_in_steps(rng: &mut impl Rng, step: u8) -> Self {
+        let display_hour = rng.random_range(1..=12);
+        let minute = rng.random_range(0..60 / step) * step;
+        let is_afternoon = rng.random_bool(0.5);
+        Self::new(display_hour, minute, is_afternoon)
+    }
+
+    // Getters for the clock component (returns 12-hour format for display)
+    pub fn display_hour(&self) -> u8 {
+        if self.hour == 0 {
+            // Midnight (00:xx) should display as 12
+            12
+        } else if self.hour > 12 {
+            self.hour - 12
+        } else {
+            self.hour
+        }
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn is_afternoon(&self) -> bool {
+        self.is_afternoon
+    }
+
+    // Whether the student sets the hands instead of reading them
+    pub fn is_setting(&self) -> bool {
+        matches!(
+            self.task,
+            ClockTask::SetFromDigital | ClockTask::SetFromPhrase
+        )
+    }
+
+    // The time as it is said, e.g. "fem i halv fyra" for 3:25
+    pub fn phrase(&self) -> String {
+        time_phrase(self.hour, self.minute)
+    }
+
+    // Whether the time is answered in words instead of digits
+    pub fn answers_in_words(&self) -> bool {
+        self.task == ClockTask::ReadAsPhrase
+    }
+
+    fn period_phrase(&self) -> &'static str {
+        if self.is_afternoon {
+            "på eftermiddagen"
+        } else {
+            "på förmiddagen"
+        }
+    }
+
+    pub fn hour_angle(&self) -> f64 {
+        let display_hour = self.display_hour();
+        let hour_degrees = (display_hour as f64) * 30.0;
+
+        let minute_offset = (self.minute as f64) * 0.5;
+
+        hour_degrees + minute_offset
+    }
+
+    pub fn minute_angle(&self) -> f64 {
+        // Each minute is 6 degrees (360/60)
+        (self.minute as f64) * 6.0
+    }
+}
+
+impl Question for ClockReadingQuestion {
+    fn prompt(&self) -> String {
+        match self.task {
+            ClockTask::Read => "Vad visar klockan?".to_string(),
+            ClockTask::ReadAsPhrase => "Vad visar klockan? Svara med ord.".to_string(),
+            ClockTask::SetFromDigital => format!("Ställ klockan på {}.", self.answer()),
+            ClockTask::SetFromPhrase => {
+                format!(
+                    "Ställ klockan på {} {}.",
+                    self.phrase(),
+                    self.period_phrase()
+                )
+            }
+        }
+    }
+
+    // Typed in 24-hour format, "HH:MM" or "HHMM". The clock the hands are set
+    // on answers in the same format.
+    fn answer(&self) -> Answer {
+        if self.answers_in_words() {
+            return Answer::ClockPhrase {
+                hour: self.hour,
+                minute: self.minute,
+            };
+        }
+        Answer::ClockTime {
+            hour: self.hour,
+            minute: self.minute,
+        }
+    }
+
+    // The time may also be said in words, e.g. "kvart över tre", with the part
+    // of the day taken from the clock
+    fn check_answer(&self, answer: &str) -> bool {
+        self.answer().check(answer)
+            || Answer::ClockPhrase {
+                hour: self.hour,
+                minute: self.minute,
+            }
+            .check(answer)
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        if self.is_setting() {
+            return self.setting_explanation();
+        }
+        let display_hour = self.display_hour();
+        let hour_step = if self.minute == 0 {
+            format!("Den korta visaren pekar på {}.", display_hour)
+        } else {
+            format!(
+                "Den korta visaren har passerat {}, så timmen är {}.",
+                display_hour, display_hour
+            )
+        };
+        // Each number on the dial is five minutes apart
+        let minute_step = if self.minute == 0 {
+            "Den långa visaren pekar på 12, alltså 0 minuter.".to_string()
+        } else if self.minute.is_multiple_of(5) {
+            format!(
+                "Den långa visaren pekar på {}, och varje siffra är 5 minuter: {} × 5 = {} minuter.",
+                self.minute / 5,
+                self.minute / 5,
+                self.minute
+            )
+        } else {
+            format!(
+                "Den långa visaren står {} små streck efter 12, alltså {} minuter.",
+                self.minute, self.minute
+            )
+        };
+        if self.answers_in_words() {
+            return vec![hour_step, minute_step, self.phrase_step()];
+        }
+        let day_step = match (self.is_afternoon, display_hour) {
+            (true, 12) => "Det är eftermiddag, och klockan 12 på dagen skrivs 12.".to_string(),
+            (true, _) => format!(
+                "Det är eftermiddag, så lägg till 12 timmar: {} + 12 = {}.",
+                display_hour, self.hour
+            ),
+            (false, 12) => "Det är förmiddag, och klockan 12 på natten skrivs 00.".to_string(),
+            (false, _) => format!("Det är förmiddag, så timmen skrivs {:02}.", self.hour),
+        };
+        vec![
+            hour_step,
+            minute_step,
+            day_step,
+            format!("Klockan är {}.", self.answer()),
+        ]
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        if self.is_setting() {
+            QuestionView::SetClock(self)
+        } else {
+            QuestionView::Clock(self)
+        }
+    }
+}
+
+impl ClockReadingQuestion {
+    // How the minutes are told from the nearest full or half hour
+    fn phrase_step(&self) -> String {
+        let display_hour = self.display_hour();
+        let next_hour = display_hour % 12 + 1;
+        let phrase = self.phrase();
+        match self.minute {
+            0 => format!("Det är jämnt {}, så man säger {}.", display_hour, phrase),
+            15 => format!(
+                "15 minuter är en kvart efter {}, så man säger {}.",
+                display_hour, phrase
+            ),
+            1..=20 => format!(
+                "Det har gått {} minuter efter {}, så man säger {}.",
+                self.minute, display_hour, phrase
+            ),
+            30 => format!(
+                "Det är en halvtimme kvar till {}, så man säger {}.",
+                next_hour, phrase
+            ),
+            21..=39 => format!(
+                "Halv {} är {}:30, och det är {} minuter {}, så man säger {}.",
+                next_hour,
+                display_hour,
+                self.minute.abs_diff(30),
+                if self.minute < 30 { "före" } else { "efter" },
+                phrase
+            ),
+            45 => format!(
+                "Det är en kvart kvar till {}, så man säger {}.",
+                next_hour, phrase
+            ),
+            _ => format!(
+                "Det är {} minuter kvar till {}, så man säger {}.",
+                60 - self.minute,
+                next_hour,
+                phrase
+            ),
+        }
+    }
+
+    fn setting_explanation(&self) -> Vec<String> {
+        let display_hour = self.display_hour();
+        let mut steps = Vec::new();
+        if self.task == ClockTask::SetFromPhrase {
+            steps.push(format!(
+                "{} är {}:{:02}.",
+                capitalized(&self.phrase()),
+                display_hour,
+                self.minute
+            ));
+        }
+        steps.push(if self.minute == 0 {
+            "Den långa visaren ska peka rakt upp på 12.".to_string()
+        } else {
+            format!(
+                "{} minuter är {} × 5, så den långa visaren ska peka på {}.",
+                self.minute,
+                self.minute / 5,
+                self.minute / 5
+            )
+        });
+        steps.push(if self.minute == 0 {
+            format!("Den korta visaren ska peka på {}.", display_hour)
+        } else {
+            format!(
+                "Den korta visaren ska stå mellan {} och {}, eftersom timmen har gått en bit.",
+                display_hour,
+                display_hour % 12 + 1
+            )
+        });
+        steps.push(match (self.task, self.is_afternoon) {
+            (ClockTask::SetFromDigital, true) => format!(
+                "Timmen {} är 12 eller mer, så det är eftermiddag.",
+                self.hour
+            ),
+            (ClockTask::SetFromDigital, false) => format!(
+                "Timmen {:02} är mindre än 12, så det är förmiddag.",
+                self.hour
+            ),
+            (_, true) => "Välj eftermiddag.".to_string(),
+            (_, false) => "Välj förmiddag.".to_string(),
+        });
+        steps
+    }
+}
+
+fn capitalized(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn reads_time_phrases() {
+        assert_eq!(parse_time_phrase("kvart över tre"), Some((3, 15, None)));
+        assert_eq!(parse_time_phrase("halv fem"), Some((4, 30, None)));
+        assert_eq!(parse_time_phrase("fem i halv åtta"), Some((7, 25, None)));
+        assert_eq!(parse_time_phrase("tjugo över 2"), Some((2, 20, None)));
+        assert_eq!(
+            parse_time_phrase("Klockan är kvart i tolv på kvällen."),
+            Some((11, 45, Some(true)))
+        );
+        assert_eq!(parse_time_phrase("halv"), None);
+        assert_eq!(parse_time_phrase("kvart över tretton"), None);
+    }
+
+    #[test]
+    fn setting_uses_the_step_of_the_difficulty() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for (difficulty, step) in [
+            (Difficulty::Easy, 60),
+            (Difficulty::Normal, 15),
+            (Difficulty::Hard, 5),
+        ] {
+            for _ in 0..200 {
+                let question = ClockReadingQuestion::random_setting(&mut rng, difficulty);
+                assert_eq!(question.minute() % step, 0, "{:?}", difficulty);
+            }
+        }
+    }
+
+    #[test]
+    fn phrases_read_back_as_the_same_time() {
+        for hour in 0..24 {
+            for minute in 0..60 {
+                assert_eq!(
+                    parse_time_phrase(&time_phrase(hour, minute)),
+                    Some(((hour + 11) % 12 + 1, minute, None))
+                );
+            }
+        }
+    }
+}
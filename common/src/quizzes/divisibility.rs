@@ -0,0 +1,249 @@
+use crate::{
+    answer::Answer,
+    quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView},
+};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+// The divisors there are simple rules for
+const DIVISORS: [u32; 7] = [2, 3, 4, 5, 6, 9, 10];
+
+// How many divisors a Hard question lets the student pick from
+const DIVISORS_PER_SET: usize = 4;
+
+// Smallest and largest number asked about, and the divisors used, per difficulty.
+// Easy only has the rules that look at the last digit.
+fn settings(difficulty: Difficulty) -> (u32, u32, &'static [u32]) {
+    match difficulty {
+        Difficulty::Easy => (10, 100, &[2, 5, 10]),
+        Difficulty::Normal => (10, 1000, &DIVISORS),
+        Difficulty::Hard => (100, 10000, &DIVISORS),
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn digits(number: u32) -> Vec<u32> {
+    number
+        .to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .collect()
+}
+
+fn divisible_text(divisible: bool) -> &'static str {
+    if divisible { "delbart" } else { "inte delbart" }
+}
+
+// The rule for a divisor, as shown before it is used
+fn rule(divisor: u32) -> String {
+    match divisor {
+        2 => "Ett tal är delbart med 2 om sista siffran är jämn.".to_string(),
+        3 | 9 => format!(
+            "Ett tal är delbart med {} om siffersumman är delbar med {}.",
+            divisor, divisor
+        ),
+        4 => {
+            "Ett tal är delbart med 4 om talet som de två sista siffrorna bildar är delbart med 4."
+                .to_string()
+        }
+        5 => "Ett tal är delbart med 5 om sista siffran är 0 eller 5.".to_string(),
+        6 => "Ett tal är delbart med 6 om det är delbart med både 2 och 3.".to_string(),
+        _ => "Ett tal är delbart med 10 om sista siffran är 0.".to_string(),
+    }
+}
+
+// The rule applied to the number
+fn check(number: u32, divisor: u32) -> String {
+    let divisible = number.is_multiple_of(divisor);
+    let conclusion = format!(
+        "så {} är {} med {}.",
+        number,
+        divisible_text(divisible),
+        divisor
+    );
+    let last_digit = number % 10;
+    match divisor {
+        2 => format!(
+            "Sista siffran är {}, som är {}, {}",
+            last_digit,
+            if divisible { "jämn" } else { "udda" },
+            conclusion
+        ),
+        3 | 9 => {
+            let digits = digits(number);
+            let terms: Vec<String> = digits.iter().map(|digit| digit.to_string()).collect();
+            format!(
+                "Siffersumman är {} = {}, som {} delbar med {}, {}",
+                terms.join(" + "),
+                digits.iter().sum::<u32>(),
+                if divisible { "är" } else { "inte är" },
+                divisor,
+                conclusion
+            )
+        }
+        4 => format!(
+            "De två sista siffrorna bildar {}, som {} delbart med 4, {}",
+            number % 100,
+            if divisible { "är" } else { "inte är" },
+            conclusion
+        ),
+        6 => format!(
+            "{} är {} med 2 och {} med 3, {}",
+            number,
+            divisible_text(number.is_multiple_of(2)),
+            divisible_text(number.is_multiple_of(3)),
+            conclusion
+        ),
+        5 | 10 => format!("Sista siffran är {}, {}", last_digit, conclusion),
+        _ => conclusion,
+    }
+}
+
+// Asks whether a number is divisible by one divisor, answered yes or no, or on
+// Hard which of a set of divisors divide it, answered by picking all of them
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivisibilityQuestion {
+    number: u32,
+    divisors: Vec<u32>,
+}
+
+impl DivisibilityQuestion {
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (min, max, divisors) = settings(difficulty);
+
+        if difficulty == Difficulty::Hard {
+            let mut set: Vec<u32> = divisors
+                .choose_multiple(rng, DIVISORS_PER_SET)
+                .copied()
+                .collect();
+            set.sort_unstable();
+            // A multiple of a random part of the set, so that about half of the
+            // divisors tend to divide it
+            let multiple_of = set
+                .iter()
+                .filter(|_| rng.random_bool(0.5))
+                .fold(1, |lcm, divisor| lcm * divisor / gcd(lcm, *divisor));
+            let number =
+                multiple_of * rng.random_range(min.div_ceil(multiple_of)..=max / multiple_of);
+            return Self {
+                number,
+                divisors: set,
+            };
+        }
+
+        let divisor = *divisors.choose(rng).unwrap_or(&2);
+        // Half of the questions have the answer yes
+        let number = if rng.random_bool(0.5) {
+            divisor * rng.random_range(min.div_ceil(divisor)..=max / divisor)
+        } else {
+            loop {
+                let number = rng.random_range(min..=max);
+                if !number.is_multiple_of(divisor) {
+                    break number;
+                }
+            }
+        };
+        Self {
+            number,
+            divisors: vec![divisor],
+        }
+    }
+
+    fn dividing(&self) -> Vec<u32> {
+        self.divisors
+            .iter()
+            .copied()
+            .filter(|divisor| self.number.is_multiple_of(*divisor))
+            .collect()
+    }
+}
+
+impl Question for DivisibilityQuestion {
+    fn prompt(&self) -> String {
+        match self.divisors.as_slice() {
+            [divisor] => format!("Är {} delbart med {}?", self.number, divisor),
+            _ => format!(
+                "Vilka av talen är {} delbart med? Välj alla som stämmer.",
+                self.number
+            ),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        match self.divisors.as_slice() {
+            [divisor] => {
+                let divisible = self.number.is_multiple_of(*divisor);
+                Answer::Choice {
+                    index: if divisible { 0 } else { 1 },
+                    label: if divisible { "Ja" } else { "Nej" }.to_string(),
+                }
+            }
+            _ => {
+                let dividing = self.dividing();
+                let label = if dividing.is_empty() {
+                    "Inget av talen".to_string()
+                } else {
+                    let texts: Vec<String> =
+                        dividing.iter().map(|divisor| divisor.to_string()).collect();
+                    texts.join(", ")
+                };
+                Answer::Choices {
+                    indices: self
+                        .divisors
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, divisor)| self.number.is_multiple_of(**divisor))
+                        .map(|(index, _)| index)
+                        .collect(),
+                    label,
+                }
+            }
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        match self.divisors.as_slice() {
+            [divisor] => vec![rule(*divisor), check(self.number, *divisor)],
+            _ => {
+                let mut steps: Vec<String> = self
+                    .divisors
+                    .iter()
+                    .map(|divisor| check(self.number, *divisor))
+                    .collect();
+                steps.push(format!("Rätt val: {}", self.answer()));
+                steps
+            }
+        }
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        match self.divisors.len() {
+            1 => QuestionView::Choices(self.get_choices()),
+            _ => QuestionView::MultipleChoices(self.get_choices()),
+        }
+    }
+}
+
+impl MultipleChoiceQuestionProvider for DivisibilityQuestion {
+    fn get_choices(&self) -> Vec<Choice> {
+        let texts: Vec<String> = match self.divisors.len() {
+            1 => vec!["Ja".to_string(), "Nej".to_string()],
+            _ => self
+                .divisors
+                .iter()
+                .map(|divisor| divisor.to_string())
+                .collect(),
+        };
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| Choice {
+                display_text: text,
+                value: index.to_string(), // The value to check against the answer
+            })
+            .collect()
+    }
+}
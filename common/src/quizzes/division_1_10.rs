@@ -0,0 +1,82 @@
+use crate::{
+    answer::{Answer, Tolerance},
+    number::format_number,
+    quiz::*,
+};
+use rand::Rng;
+
+// Range of the numerator per difficulty
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 5),
+        Difficulty::Normal => (1, 10),
+        Difficulty::Hard => (5, 10),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivisionQuestion1_10 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl DivisionQuestion1_10 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result: f64 = first as f64 / second as f64;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::decimal(result, Tolerance::decimals(1)),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        let numerator = rng.random_range(range.0..=range.1);
+
+        Self::new(numerator, rng.random_range(range.0..=numerator))
+    }
+}
+
+impl Question for DivisionQuestion1_10 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} / {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (numerator, denominator) = (self.first_number, self.second_number);
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        let mut steps = vec![format!(
+            "{} går {} gånger i {}, eftersom {} × {} = {}.",
+            denominator,
+            quotient,
+            numerator,
+            denominator,
+            quotient,
+            denominator * quotient
+        )];
+        if remainder != 0 {
+            steps.push(format!(
+                "Det blir {} - {} = {} kvar, och {} / {} = {}.",
+                numerator,
+                denominator * quotient,
+                remainder,
+                remainder,
+                denominator,
+                format_number(remainder as f64 / denominator as f64)
+            ));
+        }
+        steps.push(self.answer.equation(
+            &format!("{} / {}", numerator, denominator),
+            numerator as f64 / denominator as f64,
+        ));
+        steps
+    }
+}
@@ -0,0 +1,438 @@
+use crate::{
+    answer::Answer,
+    number::parse_rational,
+    quiz::{Difficulty, Question, QuestionView},
+    quizzes::ClockReadingQuestion,
+};
+use rand::Rng;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+// Shows a time of day as HH:MM, wrapping past midnight
+fn clock_text(time: u32) -> String {
+    let time = time % MINUTES_PER_DAY;
+    format!("{:02}:{:02}", time / 60, time % 60)
+}
+
+// A duration as it is written in the answers, e.g. "1 h 25 min", "45 min" or "2 h"
+pub fn format_duration(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{} min", minutes),
+        (hours, 0) => format!("{} h", hours),
+        (hours, minutes) => format!("{} h {} min", hours, minutes),
+    }
+}
+
+// Reads a duration in minutes from forms like "1 h 25 min", "1 timme 25 minuter",
+// "85 min", "1:25", "1,5 h" or a bare number of minutes
+pub fn parse_duration(input: &str) -> Option<u32> {
+    let input = input.trim().to_lowercase();
+    if let Some((hours, minutes)) = input.split_once(':') {
+        let (hours, minutes) = (hours.trim().parse::<u32>().ok()?, minutes.trim());
+        if minutes.len() != 2 {
+            return None;
+        }
+        let minutes = minutes
+            .parse::<u32>()
+            .ok()
+            .filter(|minutes| *minutes < 60)?;
+        return hours.checked_mul(60)?.checked_add(minutes);
+    }
+
+    // Split into numbers and the units after them, e.g. "1h25min" into 1, h, 25, min
+    let mut parts: Vec<(String, String)> = Vec::new();
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        let is_number = c.is_ascii_digit() || c == ',' || c == '.';
+        match parts.last_mut() {
+            Some((_, unit)) if !is_number => unit.push(c),
+            Some((number, unit)) if unit.is_empty() => number.push(c),
+            _ if is_number => parts.push((c.to_string(), String::new())),
+            _ => return None,
+        }
+    }
+
+    let mut total = 0.0;
+    let mut last_unit = 0.0;
+    for (index, (number, unit)) in parts.iter().enumerate() {
+        let unit_minutes = match unit.as_str() {
+            "h" | "t" | "tim" | "timme" | "timmar" => 60.0,
+            "min" | "m" | "minut" | "minuter" => 1.0,
+            // A bare number is minutes
+            "" if parts.len() == 1 => 1.0,
+            _ => return None,
+        };
+        // Hours come before minutes, each at most once
+        if index > 0 && unit_minutes >= last_unit {
+            return None;
+        }
+        last_unit = unit_minutes;
+        let (numerator, denominator) = parse_rational(number)?;
+        total += numerator as f64 / denominator as f64 * unit_minutes;
+    }
+    (!parts.is_empty() && (0.0..=u32::MAX as f64).contains(&total) && total.fract() == 0.0)
+        .then_some(total as u32)
+}
+
+// Something that starts and ends at a time of day, and how it is talked about
+#[derive(Debug, PartialEq)]
+struct Event {
+    subject: &'static str,
+    starts: &'static str,
+    ends: &'static str,
+    // Says how long it lasts, followed by the duration
+    lasts: &'static str,
+    length_question: &'static str,
+    end_question: &'static str,
+    // Labels of the start and end times on clocks and in timetables
+    start_caption: &'static str,
+    end_caption: &'static str,
+}
+
+static EVENTS: [Event; 4] = [
+    Event {
+        subject: "Bussen",
+        starts: "går",
+        ends: "är framme",
+        lasts: "och resan tar",
+        length_question: "Hur lång tid tar resan?",
+        end_question: "När är bussen framme?",
+        start_caption: "Avgång",
+        end_caption: "Ankomst",
+    },
+    Event {
+        subject: "Tåget",
+        starts: "går",
+        ends: "är framme",
+        lasts: "och resan tar",
+        length_question: "Hur lång tid tar resan?",
+        end_question: "När är tåget framme?",
+        start_caption: "Avgång",
+        end_caption: "Ankomst",
+    },
+    Event {
+        subject: "Filmen",
+        starts: "börjar",
+        ends: "slutar",
+        lasts: "och håller på i",
+        length_question: "Hur lång är filmen?",
+        end_question: "När slutar filmen?",
+        start_caption: "Början",
+        end_caption: "Slut",
+    },
+    Event {
+        subject: "Matchen",
+        starts: "börjar",
+        ends: "slutar",
+        lasts: "och håller på i",
+        length_question: "Hur länge håller matchen på?",
+        end_question: "När slutar matchen?",
+        start_caption: "Start",
+        end_caption: "Slut",
+    },
+];
+
+// How the start and end times are shown when the duration is asked for
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Shown {
+    InText,
+    Clocks,
+    Timetable,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Task {
+    // How long it lasts, from the start and end times
+    Length(Shown),
+    // When it ends, from the start time and how long it lasts
+    EndTime,
+}
+
+// Durations between two times of day, and adding a duration to a time. On Hard
+// the times may be on either side of midnight.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DurationQuestion {
+    // Minutes after midnight
+    start: u32,
+    minutes: u32,
+    event: &'static Event,
+    task: Task,
+}
+
+impl DurationQuestion {
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let event = &EVENTS[rng.random_range(0..EVENTS.len())];
+        // Times and durations in five minutes up to Hard
+        let (start, minutes) = match difficulty {
+            Difficulty::Easy => (
+                rng.random_range(7 * 12..20 * 12) * 5,
+                rng.random_range(3..=24) * 5,
+            ),
+            Difficulty::Normal => (
+                rng.random_range(6 * 12..20 * 12) * 5,
+                rng.random_range(4..=48) * 5,
+            ),
+            Difficulty::Hard if rng.random_bool(0.4) => {
+                // Starts late in the evening and ends after midnight
+                let start = rng.random_range(21 * 60..MINUTES_PER_DAY);
+                (
+                    start,
+                    rng.random_range(MINUTES_PER_DAY - start + 1..=MINUTES_PER_DAY - start + 180),
+                )
+            }
+            Difficulty::Hard => {
+                let start = rng.random_range(5 * 60..18 * 60);
+                (start, rng.random_range(20..=360))
+            }
+        };
+        let task = if rng.random_bool(0.6) {
+            Task::Length(match difficulty {
+                Difficulty::Easy => Shown::Clocks,
+                Difficulty::Normal => {
+                    [Shown::InText, Shown::Clocks, Shown::Timetable][rng.random_range(0..3)]
+                }
+                Difficulty::Hard => [Shown::InText, Shown::Timetable][rng.random_range(0..2)],
+            })
+        } else {
+            Task::EndTime
+        };
+        Self {
+            start,
+            minutes,
+            event,
+            task,
+        }
+    }
+
+    fn end(&self) -> u32 {
+        (self.start + self.minutes) % MINUTES_PER_DAY
+    }
+
+    fn clock(time: u32) -> ClockReadingQuestion {
+        let hour = (time / 60) as u8;
+        ClockReadingQuestion::new((hour + 11) % 12 + 1, (time % 60) as u8, hour >= 12)
+    }
+
+    // Counting from the start to the end: up to the next full hour, the full
+    // hours, and the minutes after the last full hour
+    fn counting_steps(&self) -> Vec<String> {
+        let end = self.start + self.minutes;
+        let next_hour = self.start.next_multiple_of(60);
+        if end <= next_hour {
+            return vec![format!(
+                "Från {} till {} är det {} − {} = {} min.",
+                clock_text(self.start),
+                clock_text(end),
+                if end == next_hour { 60 } else { end % 60 },
+                self.start % 60,
+                self.minutes
+            )];
+        }
+
+        let last_hour = end / 60 * 60;
+        let mut steps = Vec::new();
+        let mut parts = Vec::new();
+        if next_hour > self.start {
+            steps.push(format!(
+                "Från {} till {} är det {} min.",
+                clock_text(self.start),
+                clock_text(next_hour),
+                next_hour - self.start
+            ));
+            parts.push(format_duration(next_hour - self.start));
+        }
+        if last_hour > next_hour {
+            steps.push(format!(
+                "Från {} till {} är det {}.",
+                clock_text(next_hour),
+                clock_text(last_hour),
+                format_duration(last_hour - next_hour)
+            ));
+            parts.push(format_duration(last_hour - next_hour));
+        }
+        if end > last_hour {
+            steps.push(format!(
+                "Från {} till {} är det {} min.",
+                clock_text(last_hour),
+                clock_text(end),
+                end - last_hour
+            ));
+            parts.push(format_duration(end - last_hour));
+        }
+        if parts.len() > 1 {
+            steps.push(format!(
+                "Sammanlagt {} = {}.",
+                parts.join(" + "),
+                format_duration(self.minutes)
+            ));
+        }
+        steps
+    }
+
+    // Adding the hours first and then the minutes, via the next full hour when
+    // the minutes go past it
+    fn adding_steps(&self) -> Vec<String> {
+        let hours = self.minutes / 60 * 60;
+        let rest = self.minutes % 60;
+        let mut time = self.start;
+        let mut steps = Vec::new();
+        if hours > 0 {
+            steps.push(format!(
+                "Lägg till {}: {} blir {}.",
+                format_duration(hours),
+                clock_text(time),
+                clock_text(time + hours)
+            ));
+            time += hours;
+        }
+        let next_hour = time.next_multiple_of(60);
+        if rest > 0 && time + rest > next_hour && time < next_hour {
+            steps.push(format!(
+                "{} min till hel timme: {} blir {}.",
+                next_hour - time,
+                clock_text(time),
+                clock_text(next_hour)
+            ));
+            steps.push(format!(
+                "Lägg till de {} min som är kvar: {} blir {}.",
+                time + rest - next_hour,
+                clock_text(next_hour),
+                clock_text(time + rest)
+            ));
+        } else if rest > 0 {
+            steps.push(format!(
+                "Lägg till {} min: {} blir {}.",
+                rest,
+                clock_text(time),
+                clock_text(time + rest)
+            ));
+        }
+        steps
+    }
+
+    fn crosses_midnight(&self) -> bool {
+        self.start + self.minutes > MINUTES_PER_DAY
+    }
+}
+
+impl Question for DurationQuestion {
+    fn prompt(&self) -> String {
+        let event = self.event;
+        match self.task {
+            Task::Length(Shown::InText) => format!(
+                "{} {} {} och {} {}. {}",
+                event.subject,
+                event.starts,
+                clock_text(self.start),
+                event.ends,
+                clock_text(self.end()),
+                event.length_question
+            ),
+            Task::Length(shown) => format!(
+                "{} visar {} och {}. {}",
+                if shown == Shown::Clocks {
+                    "Klockorna"
+                } else {
+                    "Tabellen"
+                },
+                event.start_caption.to_lowercase(),
+                event.end_caption.to_lowercase(),
+                event.length_question
+            ),
+            Task::EndTime => format!(
+                "{} {} {} {} {}. {}",
+                event.subject,
+                event.starts,
+                clock_text(self.start),
+                event.lasts,
+                format_duration(self.minutes),
+                event.end_question
+            ),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        match self.task {
+            Task::Length(_) => Answer::Duration(self.minutes),
+            Task::EndTime => Answer::ClockTime {
+                hour: (self.end() / 60) as u8,
+                minute: (self.end() % 60) as u8,
+            },
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let mut steps = match self.task {
+            Task::Length(_) => self.counting_steps(),
+            Task::EndTime => self.adding_steps(),
+        };
+        if self.crosses_midnight() {
+            steps.push("Efter 23:59 börjar ett nytt dygn, så 24:00 skrivs 00:00.".to_string());
+        }
+        steps.push(match self.task {
+            Task::Length(_) => format!("Det tar {}.", format_duration(self.minutes)),
+            Task::EndTime => format!(
+                "{} {} {}.",
+                self.event.subject,
+                self.event.ends,
+                clock_text(self.end())
+            ),
+        });
+        steps
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        let times = [
+            (self.event.start_caption, self.start),
+            (self.event.end_caption, self.end()),
+        ];
+        match self.task {
+            Task::Length(Shown::Clocks) => QuestionView::Clocks(
+                times
+                    .iter()
+                    .map(|(caption, time)| (*caption, Self::clock(*time)))
+                    .collect(),
+            ),
+            Task::Length(Shown::Timetable) => QuestionView::Timetable(
+                times
+                    .iter()
+                    .map(|(caption, time)| (*caption, clock_text(*time)))
+                    .collect(),
+            ),
+            _ => QuestionView::Text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_durations() {
+        assert_eq!(parse_duration("1 h 25 min"), Some(85));
+        assert_eq!(parse_duration("85 min"), Some(85));
+        assert_eq!(parse_duration("1:25"), Some(85));
+        assert_eq!(parse_duration("1 timme 25 minuter"), Some(85));
+        assert_eq!(parse_duration("1,5 h"), Some(90));
+        assert_eq!(parse_duration("85"), Some(85));
+        assert_eq!(parse_duration("25 min 1 h"), None);
+        assert_eq!(parse_duration("1:5"), None);
+        assert_eq!(parse_duration("1 h 1 h"), None);
+    }
+
+    #[test]
+    fn rejects_durations_too_long_to_store() {
+        assert_eq!(parse_duration("71582788:15"), Some(u32::MAX));
+        assert_eq!(parse_duration("71582788:16"), None);
+        assert_eq!(parse_duration("99999999:00"), None);
+        assert_eq!(parse_duration("99999999 h"), None);
+        assert_eq!(parse_duration("99999999999 min"), None);
+    }
+
+    #[test]
+    fn formatted_durations_read_back() {
+        for minutes in 0..600 {
+            assert_eq!(parse_duration(&format_duration(minutes)), Some(minutes));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use rand::Rng;
+
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question},
+};
+
+// Ranges of the factors and of the values put in for x and y, per difficulty
+fn ranges(difficulty: Difficulty) -> ((i32, i32), (i32, i32)) {
+    match difficulty {
+        Difficulty::Easy => ((1, 4), (0, 10)),
+        Difficulty::Normal => ((4, 6), (0, 20)),
+        Difficulty::Hard => ((2, 13), (-20, 21)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpressionQuestion {
+    x_factor: i32,
+    y_factor: i32,
+    x_value: i32,
+    y_value: i32,
+    sign: bool,
+    answer: Answer,
+}
+
+impl ExpressionQuestion {
+    pub fn new(x_factor: i32, y_factor: i32, x_value: i32, y_value: i32, sign: bool) -> Self {
+        let answer: i32 = match sign {
+            true => x_value * x_factor + y_value * y_factor,
+            false => x_value * x_factor - y_value * y_factor,
+        };
+
+        Self {
+            x_factor,
+            y_factor,
+            x_value,
+            y_value,
+            sign,
+            answer: Answer::Integer(answer.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (factor_range, value_range) = ranges(difficulty);
+        let x_factor: i32 = rng.random_range(factor_range.0..factor_range.1);
+        let y_factor: i32 = rng.random_range(factor_range.0..factor_range.1);
+        let x_value: i32 = rng.random_range(value_range.0..value_range.1);
+        let y_value: i32 = rng.random_range(value_range.0..value_range.1);
+        let sign = rng.random_bool(0.5);
+        Self::new(x_factor, y_factor, x_value, y_value, sign)
+    }
+}
+
+impl Question for ExpressionQuestion {
+    fn prompt(&self) -> String {
+        let sign = if self.sign { "+" } else { "-" };
+        format!(
+            "Beräkna uttryckets värde: {}x {} {}y, where x={} and y={}?",
+            self.x_factor, sign, self.y_factor, self.x_value, self.y_value
+        )
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let sign = if self.sign { "+" } else { "-" };
+        let x_term = self.x_factor * self.x_value;
+        let y_term = self.y_factor * self.y_value;
+        vec![
+            format!(
+                "Sätt in x = {} och y = {}: {} · {} {} {} · {}",
+                self.x_value,
+                self.y_value,
+                self.x_factor,
+                self.x_value,
+                sign,
+                self.y_factor,
+                self.y_value
+            ),
+            format!("Multiplicera först: {} {} {}", x_term, sign, y_term),
+            format!("{} {} {} = {}", x_term, sign, y_term, self.answer),
+        ]
+    }
+}
@@ -1,13 +1,16 @@
 use rand::Rng;
 
-use crate::quiz::Question;
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question},
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FirstDegreeDerivativeQuestion {
     // Represent a polynomial function like ax^n + bx^m + c
     terms: Vec<(i32, u32)>, // Each term is (coefficient, exponent)
     constant: i32,          // Constant term
-    answer_text: String,    // The derivative expression
+    answer: Answer,         // The derivative expression
 }
 
 impl FirstDegreeDerivativeQuestion {
@@ -26,26 +29,30 @@ impl FirstDegreeDerivativeQuestion {
         Self {
             terms,
             constant,
-            answer_text: answer,
+            answer: Answer::Expression(answer),
         }
     }
 
     // Generate a random first-degree derivative question
-    pub fn random() -> Self {
-        let mut rng = rand::rng();
-
-        // Generate between 1 and 3 terms
-        let num_terms = rng.random_range(1..4);
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        // Number of terms, coefficients and highest power per difficulty.
+        // Easy functions only have positive coefficients.
+        let (terms_range, coefficient_range, max_exponent) = match difficulty {
+            Difficulty::Easy => ((1, 2), (1, 10), 2),
+            Difficulty::Normal => ((1, 4), (-9, 10), 3),
+            Difficulty::Hard => ((2, 5), (-20, 21), 6),
+        };
+        let num_terms = rng.random_range(terms_range.0..terms_range.1);
         let mut terms = Vec::with_capacity(num_terms);
 
         // Generate terms with coefficients and exponents
         for _ in 0..num_terms {
-            let coefficient = rng.random_range(-9..10);
+            let coefficient = rng.random_range(coefficient_range.0..coefficient_range.1);
             if coefficient == 0 {
                 continue; // Skip zero terms
             }
 
-            let exponent = rng.random_range(1..4); // Power from 1 to 3
+            let exponent = rng.random_range(1..=max_exponent);
             terms.push((coefficient, exponent));
         }
 
@@ -137,19 +144,37 @@ impl Question for FirstDegreeDerivativeQuestion {
         format!("Beräkna f'(x): f(x) = {}", self.format_original())
     }
 
-    fn answer(&self) -> &str {
-        &self.answer_text
+    fn answer(&self) -> Answer {
+        self.answer.clone()
     }
 
-    // Override to handle different equivalent forms of the answer
-    fn check_answer(&self, answer: &str) -> bool {
-        // This is a simple implementation that checks exact match
-        // For a more robust solution, you'd want to parse and compare expressions algebraically
-
-        // Normalize the answer by removing spaces and converting to lowercase
-        let normalized_answer = answer.trim().to_lowercase().replace(" ", "");
-        let normalized_correct = self.answer_text.to_lowercase().replace(" ", "");
-
-        normalized_answer == normalized_correct
+    fn explanation(&self) -> Vec<String> {
+        let mut steps =
+            vec!["Derivera term för term med potensregeln: axⁿ blir a · n · xⁿ⁻¹.".to_string()];
+        for &(coefficient, exponent) in &self.terms {
+            let term = Self::format_polynomial(&[(coefficient, exponent)], 0);
+            let derivative =
+                Self::format_polynomial(&[(coefficient * exponent as i32, exponent - 1)], 0);
+            steps.push(if exponent == 1 {
+                format!("{} blir {}, eftersom x blir 1.", term, derivative)
+            } else {
+                format!(
+                    "{} blir {} · {} · x^{} = {}",
+                    term,
+                    coefficient,
+                    exponent,
+                    exponent - 1,
+                    derivative
+                )
+            });
+        }
+        if self.constant != 0 {
+            steps.push(format!(
+                "Konstanten {} försvinner, eftersom derivatan av en konstant är 0.",
+                self.constant
+            ));
+        }
+        steps.push(format!("f'(x) = {}", self.answer));
+        steps
     }
 }
@@ -0,0 +1,107 @@
+use rand::Rng;
+
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FirstOrderEquationQuestion {
+    coefficient: i32,
+    constant_left: i32,
+    constant_right: i32,
+    operation: char,
+    answer: Answer,
+}
+
+impl FirstOrderEquationQuestion {
+    pub fn new(coefficient: i32, constant_left: i32, constant_right: i32, operation: char) -> Self {
+        // Calculate the answer (x value)
+        let x = match operation {
+            '+' => (constant_right - constant_left) as f64 / coefficient as f64,
+            '-' => (constant_right + constant_left) as f64 / coefficient as f64,
+            _ => 0.0, // Default case for unsupported operations
+        };
+
+        // Fractional solutions are answered with two decimals
+        let answer = if x.fract() == 0.0 {
+            Answer::Integer(x as i64)
+        } else {
+            Answer::decimal(x, Tolerance::decimals(2))
+        };
+
+        Self {
+            coefficient,
+            constant_left,
+            constant_right,
+            operation,
+            answer,
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        // Generate values that will likely result in integer answers for simplicity.
+        // Hard equations can have negative solutions and larger constants.
+        let (coefficient_range, answer_range, max_constant) = match difficulty {
+            Difficulty::Easy => ((1, 5), (1, 6), 10),
+            Difficulty::Normal => ((1, 10), (1, 10), 15),
+            Difficulty::Hard => ((2, 15), (-10, 16), 50),
+        };
+        let coefficient = rng.random_range(coefficient_range.0..coefficient_range.1);
+        let answer = rng.random_range(answer_range.0..answer_range.1); // The actual answer (x value)
+
+        // Randomly choose operation (+ or -)
+        let operations = ['+', '-'];
+        let operation = operations[rng.random_range(0..operations.len())];
+
+        // Generate the constant values based on the desired answer
+        let constant_left = rng.random_range(1..max_constant);
+        let constant_right = match operation {
+            '+' => (coefficient * answer) + constant_left,
+            '-' => (coefficient * answer) - constant_left,
+            _ => 0, // Won't happen with our limited operations
+        };
+
+        Self::new(coefficient, constant_left, constant_right, operation)
+    }
+}
+
+impl Question for FirstOrderEquationQuestion {
+    fn prompt(&self) -> String {
+        // Format the equation nicely
+        format!(
+            "Beräkna x: {}x {} {} = {}",
+            self.coefficient, self.operation, self.constant_left, self.constant_right
+        )
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (coefficient, constant_left) = (self.coefficient, self.constant_left);
+        let right_side = match self.operation {
+            '+' => self.constant_right - constant_left,
+            _ => self.constant_right + constant_left,
+        };
+        let isolate_step = match self.operation {
+            '+' => format!(
+                "Subtrahera {} från båda sidor: {}x = {} - {} = {}",
+                constant_left, coefficient, self.constant_right, constant_left, right_side
+            ),
+            _ => format!(
+                "Addera {} till båda sidor: {}x = {} + {} = {}",
+                constant_left, coefficient, self.constant_right, constant_left, right_side
+            ),
+        };
+        vec![
+            isolate_step,
+            format!("Dividera båda sidor med {}.", coefficient),
+            self.answer.equation(
+                &format!("x = {} / {}", right_side, coefficient),
+                right_side as f64 / coefficient as f64,
+            ),
+        ]
+    }
+}
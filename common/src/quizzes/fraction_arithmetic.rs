@@ -0,0 +1,501 @@
+use std::fmt;
+
+use crate::{
+    answer::{Answer, FractionForm},
+    quiz::{Difficulty, Question},
+    quizzes::{Operator, gcd},
+};
+use rand::Rng;
+
+// Largest denominator of the fractions in a question, per difficulty
+fn max_denominator(difficulty: Difficulty) -> i32 {
+    match difficulty {
+        Difficulty::Easy => 6,
+        Difficulty::Normal => 10,
+        Difficulty::Hard => 12,
+    }
+}
+
+// Whether calculations have to be answered in lowest terms. Easy also accepts an
+// equal fraction that isn't reduced, e.g. 4/6 for 2/3. Questions that ask for
+// reducing always require it.
+fn requires_lowest_terms(difficulty: Difficulty) -> bool {
+    difficulty != Difficulty::Easy
+}
+
+fn lcm(a: i32, b: i32) -> i32 {
+    a / gcd(a, b) * b
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Fraction {
+    numerator: i32,
+    denominator: i32,
+}
+
+impl Fraction {
+    fn new(numerator: i32, denominator: i32) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    fn random_proper(rng: &mut impl Rng, max_denominator: i32) -> Self {
+        let denominator = rng.random_range(2..=max_denominator);
+        Self::new(rng.random_range(1..denominator), denominator).reduced()
+    }
+
+    fn common_factor(&self) -> i32 {
+        gcd(self.numerator.abs(), self.denominator)
+    }
+
+    fn reduced(&self) -> Self {
+        let factor = self.common_factor();
+        Self::new(self.numerator / factor, self.denominator / factor)
+    }
+
+    fn value(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    // With the whole part taken out, e.g. 2 1/3 for 7/3
+    fn mixed(&self) -> String {
+        let whole = self.numerator / self.denominator;
+        let rest = self.numerator % self.denominator;
+        match (whole, rest) {
+            (0, _) => self.to_string(),
+            (_, 0) => whole.to_string(),
+            _ => format!("{} {}/{}", whole, rest, self.denominator),
+        }
+    }
+
+    fn is_mixed(&self) -> bool {
+        self.numerator > self.denominator && self.numerator % self.denominator != 0
+    }
+
+    // Shows the step of reducing, if it can be reduced
+    fn reducing_step(&self) -> Option<String> {
+        let factor = self.common_factor();
+        (factor > 1).then(|| format!("Förkorta med {}: {} = {}", factor, self, self.reduced()))
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Task {
+    // Operands above one are shown as mixed numbers
+    Calculate {
+        left: Fraction,
+        operator: Operator,
+        right: Fraction,
+    },
+    Reduce(Fraction),
+    // From a mixed number to a fraction
+    ToImproper(Fraction),
+    // From a fraction to a mixed number
+    ToMixed(Fraction),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionArithmeticQuestion {
+    task: Task,
+    lowest_terms: bool,
+}
+
+impl FractionArithmeticQuestion {
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max = max_denominator(difficulty);
+        // Easy keeps to common denominators and reducing
+        let task = if difficulty == Difficulty::Easy {
+            match rng.random_range(0..3) {
+                0 | 1 => Self::random_same_denominator(rng, max),
+                _ => Self::random_reduce(rng, max, 3),
+            }
+        } else {
+            match rng.random_range(0..7) {
+                0..=3 => Self::random_calculation(rng, max, difficulty),
+                4 => Self::random_reduce(rng, max, 6),
+                5 => Task::ToImproper(Self::random_mixed(rng, max)),
+                _ => Task::ToMixed(Self::random_mixed(rng, max)),
+            }
+        };
+        Self {
+            task,
+            lowest_terms: requires_lowest_terms(difficulty),
+        }
+    }
+
+    fn random_same_denominator(rng: &mut impl Rng, max: i32) -> Task {
+        let denominator = rng.random_range(3..=max);
+        let first = rng.random_range(1..denominator);
+        // Two different numerators, so a subtraction never comes out as zero
+        let mut second = rng.random_range(1..denominator);
+        while second == first {
+            second = rng.random_range(1..denominator);
+        }
+        Task::Calculate {
+            left: Fraction::new(first.max(second), denominator),
+            operator: if rng.random_bool(0.5) {
+                Operator::Add
+            } else {
+                Operator::Subtract
+            },
+            right: Fraction::new(first.min(second), denominator),
+        }
+    }
+
+    fn random_calculation(rng: &mut impl Rng, max: i32, difficulty: Difficulty) -> Task {
+        let operator = [
+            Operator::Add,
+            Operator::Subtract,
+            Operator::Multiply,
+            Operator::Divide,
+        ][rng.random_range(0..4)];
+        let mut left = Self::random_operand(rng, max, difficulty);
+        let mut right = Self::random_operand(rng, max, difficulty);
+        // Operands are reduced, so equal ones are the same fraction. Differences
+        // are kept positive, never zero.
+        while operator == Operator::Subtract && left == right {
+            right = Self::random_operand(rng, max, difficulty);
+        }
+        if operator == Operator::Subtract && left.value() < right.value() {
+            (left, right) = (right, left);
+        }
+        Task::Calculate {
+            left,
+            operator,
+            right,
+        }
+    }
+
+    // A reduced fraction expanded by up to `max_factor`
+    fn random_reduce(rng: &mut impl Rng, max: i32, max_factor: i32) -> Task {
+        let fraction = Fraction::random_proper(rng, max);
+        let factor = rng.random_range(2..=max_factor);
+        Task::Reduce(Fraction::new(
+            fraction.numerator * factor,
+            fraction.denominator * factor,
+        ))
+    }
+
+    // Hard also uses mixed numbers
+    fn random_operand(rng: &mut impl Rng, max: i32, difficulty: Difficulty) -> Fraction {
+        if difficulty == Difficulty::Hard && rng.random_bool(0.3) {
+            Self::random_mixed(rng, max)
+        } else {
+            Fraction::random_proper(rng, max)
+        }
+    }
+
+    // A fraction above one that isn't a whole number
+    fn random_mixed(rng: &mut impl Rng, max: i32) -> Fraction {
+        let whole = rng.random_range(1..=5);
+        let part = Fraction::random_proper(rng, max);
+        Fraction::new(whole * part.denominator + part.numerator, part.denominator)
+    }
+
+    // The exact result, before it is reduced
+    fn result(&self) -> Fraction {
+        match &self.task {
+            Task::Calculate {
+                left,
+                operator,
+                right,
+            } => match operator {
+                Operator::Add | Operator::Subtract => {
+                    let denominator = lcm(left.denominator, right.denominator);
+                    let left_numerator = left.numerator * (denominator / left.denominator);
+                    let right_numerator = right.numerator * (denominator / right.denominator);
+                    let numerator = if *operator == Operator::Add {
+                        left_numerator + right_numerator
+                    } else {
+                        left_numerator - right_numerator
+                    };
+                    Fraction::new(numerator, denominator)
+                }
+                Operator::Multiply => Fraction::new(
+                    left.numerator * right.numerator,
+                    left.denominator * right.denominator,
+                ),
+                Operator::Divide => Fraction::new(
+                    left.numerator * right.denominator,
+                    left.denominator * right.numerator,
+                ),
+            },
+            Task::Reduce(fraction) | Task::ToImproper(fraction) | Task::ToMixed(fraction) => {
+                *fraction
+            }
+        }
+    }
+}
+
+impl Question for FractionArithmeticQuestion {
+    fn prompt(&self) -> String {
+        match &self.task {
+            Task::Calculate {
+                left,
+                operator,
+                right,
+            } => {
+                let instruction = if self.lowest_terms {
+                    "Svara med ett bråk i enklaste form."
+                } else {
+                    "Svara med ett bråk."
+                };
+                format!(
+                    "Beräkna {} {} {}. {}",
+                    left.mixed(),
+                    operator,
+                    right.mixed(),
+                    instruction
+                )
+            }
+            Task::Reduce(fraction) => format!("Förkorta {} så långt det går.", fraction),
+            Task::ToImproper(fraction) => {
+                format!("Skriv det blandade talet {} i bråkform.", fraction.mixed())
+            }
+            Task::ToMixed(fraction) => format!("Skriv {} i blandad form.", fraction),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        let result = self.result().reduced();
+        let (form, lowest_terms) = match &self.task {
+            Task::Calculate { .. } => (FractionForm::Fraction, self.lowest_terms),
+            Task::Reduce(_) => (FractionForm::Fraction, true),
+            Task::ToImproper(_) => (FractionForm::Improper, self.lowest_terms),
+            Task::ToMixed(_) => (FractionForm::Mixed, self.lowest_terms),
+        };
+        Answer::Fraction {
+            numerator: result.numerator.into(),
+            denominator: result.denominator.into(),
+            form,
+            lowest_terms,
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let result = self.result();
+        let mut steps = Vec::new();
+        match &self.task {
+            Task::Calculate {
+                left,
+                operator,
+                right,
+            } => {
+                let operands: Vec<&Fraction> =
+                    [left, right].into_iter().filter(|f| f.is_mixed()).collect();
+                if !operands.is_empty() {
+                    let rewritten: Vec<String> = operands
+                        .iter()
+                        .map(|fraction| format!("{} = {}", fraction.mixed(), fraction))
+                        .collect();
+                    steps.push(format!(
+                        "Skriv blandade tal i bråkform: {}",
+                        rewritten.join(" och ")
+                    ));
+                }
+                match operator {
+                    Operator::Add | Operator::Subtract if left.denominator == right.denominator => {
+                        steps.push(format!(
+                            "Nämnarna är lika, så räkna med täljarna: {} {} {} = ({} {} {})/{} = {}",
+                            left,
+                            operator,
+                            right,
+                            left.numerator,
+                            operator,
+                            right.numerator,
+                            left.denominator,
+                            result
+                        ));
+                    }
+                    Operator::Add | Operator::Subtract => {
+                        let denominator = result.denominator;
+                        let expand = |fraction: &Fraction| {
+                            Fraction::new(
+                                fraction.numerator * (denominator / fraction.denominator),
+                                denominator,
+                            )
+                        };
+                        steps.push(format!(
+                            "Förläng till den gemensamma nämnaren {}: {} = {} och {} = {}",
+                            denominator,
+                            left,
+                            expand(left),
+                            right,
+                            expand(right)
+                        ));
+                        steps.push(format!(
+                            "{} {} {} = {}",
+                            expand(left),
+                            operator,
+                            expand(right),
+                            result
+                        ));
+                    }
+                    Operator::Multiply => steps.push(format!(
+                        "Multiplicera täljare med täljare och nämnare med nämnare: {} × {} = ({} × {})/({} × {}) = {}",
+                        left,
+                        right,
+                        left.numerator,
+                        right.numerator,
+                        left.denominator,
+                        right.denominator,
+                        result
+                    )),
+                    Operator::Divide => steps.push(format!(
+                        "Att dividera är att multiplicera med det inverterade bråket: {} ÷ {} = {} × {}/{} = {}",
+                        left,
+                        right,
+                        left,
+                        right.denominator,
+                        right.numerator,
+                        result
+                    )),
+                }
+                steps.extend(result.reducing_step());
+            }
+            Task::Reduce(fraction) => {
+                steps.push(format!(
+                    "Den största gemensamma delaren till {} och {} är {}.",
+                    fraction.numerator,
+                    fraction.denominator,
+                    fraction.common_factor()
+                ));
+                steps.push(format!(
+                    "{} = ({} ÷ {})/({} ÷ {}) = {}",
+                    fraction,
+                    fraction.numerator,
+                    fraction.common_factor(),
+                    fraction.denominator,
+                    fraction.common_factor(),
+                    fraction.reduced()
+                ));
+            }
+            Task::ToImproper(fraction) => {
+                let whole = fraction.numerator / fraction.denominator;
+                steps.push(format!(
+                    "Gör om heltalet till {}-delar och lägg till täljaren: {} = ({} × {} + {})/{} = {}",
+                    fraction.denominator,
+                    fraction.mixed(),
+                    whole,
+                    fraction.denominator,
+                    fraction.numerator % fraction.denominator,
+                    fraction.denominator,
+                    fraction
+                ));
+            }
+            Task::ToMixed(fraction) => {
+                steps.push(format!(
+                    "{} ÷ {} = {} med resten {}, så {} = {}",
+                    fraction.numerator,
+                    fraction.denominator,
+                    fraction.numerator / fraction.denominator,
+                    fraction.numerator % fraction.denominator,
+                    fraction,
+                    fraction.mixed()
+                ));
+            }
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn question(task: Task) -> FractionArithmeticQuestion {
+        FractionArithmeticQuestion {
+            task,
+            lowest_terms: true,
+        }
+    }
+
+    #[test]
+    fn differences_are_never_zero() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            for _ in 0..1000 {
+                let question = FractionArithmeticQuestion::random(&mut rng, difficulty);
+                assert!(question.result().numerator > 0, "{}", question.prompt());
+            }
+        }
+    }
+
+    #[test]
+    fn reducing_requires_lowest_terms() {
+        let question = question(Task::Reduce(Fraction::new(6, 8)));
+        assert_eq!(question.answer().to_string(), "3/4");
+        assert!(question.check_answer("3/4"));
+        assert!(!question.check_answer("6/8"));
+        assert!(!question.check_answer("0,75"));
+    }
+
+    #[test]
+    fn converts_between_mixed_and_improper() {
+        let to_mixed = question(Task::ToMixed(Fraction::new(7, 3)));
+        assert_eq!(to_mixed.answer().to_string(), "2 1/3");
+        assert!(to_mixed.check_answer("2 1/3"));
+        assert!(!to_mixed.check_answer("7/3"));
+
+        let to_improper = question(Task::ToImproper(Fraction::new(7, 3)));
+        assert_eq!(to_improper.answer().to_string(), "7/3");
+        assert!(to_improper.check_answer("7/3"));
+        assert!(!to_improper.check_answer("2 1/3"));
+        assert!(!to_improper.check_answer("14/6"));
+    }
+
+    #[test]
+    fn calculations_are_answered_in_lowest_terms() {
+        // 1/6 + 1/3 = 3/6 = 1/2
+        let question = question(Task::Calculate {
+            left: Fraction::new(1, 6),
+            operator: Operator::Add,
+            right: Fraction::new(1, 3),
+        });
+        assert_eq!(question.answer().to_string(), "1/2");
+        assert!(question.check_answer("1/2"));
+        assert!(!question.check_answer("3/6"));
+    }
+
+    #[test]
+    fn easy_accepts_unreduced_results() {
+        // 1/6 + 3/6 = 4/6 = 2/3
+        let calculation = Task::Calculate {
+            left: Fraction::new(3, 6),
+            operator: Operator::Add,
+            right: Fraction::new(1, 6),
+        };
+        let easy = FractionArithmeticQuestion {
+            task: calculation.clone(),
+            lowest_terms: requires_lowest_terms(Difficulty::Easy),
+        };
+        assert!(easy.check_answer("2/3"));
+        assert!(easy.check_answer("4/6"));
+        let normal = FractionArithmeticQuestion {
+            task: calculation,
+            lowest_terms: requires_lowest_terms(Difficulty::Normal),
+        };
+        assert!(normal.check_answer("2/3"));
+        assert!(!normal.check_answer("4/6"));
+
+        let reduce = FractionArithmeticQuestion {
+            task: Task::Reduce(Fraction::new(4, 6)),
+            lowest_terms: requires_lowest_terms(Difficulty::Easy),
+        };
+        assert!(!reduce.check_answer("4/6"));
+    }
+}
@@ -0,0 +1,147 @@
+use super::{NumberValue, number_comparison::comparison_step};
+use crate::{
+    answer::Answer,
+    quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView},
+};
+use rand::Rng;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionComparisonQuestion {
+    first_value: NumberValue,
+    second_value: NumberValue,
+    correct_answer: usize, // 0 for first, 1 for second, 2 for equal
+}
+
+impl FractionComparisonQuestion {
+    pub fn new(first: NumberValue, second: NumberValue) -> Self {
+        let first_val = first.as_f64();
+        let second_val = second.as_f64();
+
+        let correct_answer = match first_val.partial_cmp(&second_val).unwrap() {
+            Ordering::Greater => 0, // First is greater
+            Ordering::Less => 1,    // Second is greater
+            Ordering::Equal => 2,   // Equal
+        };
+
+        Self {
+            first_value: first,
+            second_value: second,
+            correct_answer,
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (first, second) = (
+            NumberValue::random_fraction(rng, difficulty),
+            NumberValue::random_fraction(rng, difficulty),
+        );
+
+        // Ensure they're not equal
+        if (first.as_f64() - second.as_f64()).abs() < 0.0001 {
+            // If they're too close, just return a new random one
+            return Self::random(rng, difficulty);
+        }
+
+        Self::new(first, second)
+    }
+
+    pub fn first_value(&self) -> &NumberValue {
+        &self.first_value
+    }
+
+    pub fn second_value(&self) -> &NumberValue {
+        &self.second_value
+    }
+
+    pub fn check_selected(&self, selected_index: usize) -> bool {
+        selected_index == self.correct_answer
+    }
+}
+
+impl Question for FractionComparisonQuestion {
+    fn prompt(&self) -> String {
+        "Vilket värde är störst".to_string()
+    }
+
+    fn answer(&self) -> Answer {
+        let label = match self.correct_answer {
+            0 => "vänster",
+            1 => "höger",
+            _ => "lika", // This shouldn't happen as we ensure they're not equal
+        };
+        Answer::Choice {
+            index: self.correct_answer,
+            label: label.to_string(),
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (
+            NumberValue::Fraction {
+                numerator: first_numerator,
+                denominator: first_denominator,
+            },
+            NumberValue::Fraction {
+                numerator: second_numerator,
+                denominator: second_denominator,
+            },
+        ) = (&self.first_value, &self.second_value)
+        else {
+            return vec![comparison_step(
+                &self.first_value,
+                &self.second_value,
+                &self.answer(),
+            )];
+        };
+
+        // Expand both fractions to the common denominator
+        let common_denominator = first_denominator * second_denominator;
+        vec![
+            format!(
+                "Förläng bråken så att de får samma nämnare, {} × {} = {}.",
+                first_denominator, second_denominator, common_denominator
+            ),
+            format!(
+                "{}/{} = {}/{}",
+                first_numerator,
+                first_denominator,
+                first_numerator * second_denominator,
+                common_denominator
+            ),
+            format!(
+                "{}/{} = {}/{}",
+                second_numerator,
+                second_denominator,
+                second_numerator * first_denominator,
+                common_denominator
+            ),
+            "Med samma nämnare är bråket med störst täljare störst.".to_string(),
+            comparison_step(&self.first_value, &self.second_value, &self.answer()),
+        ]
+    }
+
+    // Override display to customize the output
+    fn display(&self) -> String {
+        "Vilket bråk är störst?".to_string()
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Choices(self.get_choices())
+    }
+}
+
+impl MultipleChoiceQuestionProvider for FractionComparisonQuestion {
+    fn get_choices(&self) -> Vec<Choice> {
+        vec![
+            Choice {
+                display_text: self.first_value.display(),
+                value: "0".to_string(),
+            },
+            Choice {
+                display_text: self.second_value.display(),
+                value: "1".to_string(),
+            },
+        ]
+    }
+}
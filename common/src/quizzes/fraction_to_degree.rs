@@ -0,0 +1,75 @@
+use rand::Rng;
+
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+};
+
+// Range of the denominator per difficulty
+fn denominator_range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 4),
+        Difficulty::Normal => (1, 12),
+        Difficulty::Hard => (5, 24),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionToDegree {
+    nominator: i32,
+    denominator: i32,
+    answer: Answer,
+}
+
+impl FractionToDegree {
+    pub fn new(nominator: i32, denominator: i32) -> Self {
+        let answer = (nominator as f64 / denominator as f64) * 360.0;
+        Self {
+            nominator,
+            denominator,
+            answer: Answer::decimal(answer, Tolerance::decimals(1)),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = denominator_range(difficulty);
+        let denominator: i32 = rng.random_range(range.0..=range.1);
+        let nominator: i32 = rng.random_range(1..=denominator);
+        Self::new(nominator, denominator)
+    }
+}
+
+impl Question for FractionToDegree {
+    fn prompt(&self) -> String {
+        format!(
+            "Hur många grader är {}/{}-delar av en cirkel?",
+            self.nominator, self.denominator
+        )
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        vec![
+            "Ett helt varv är 360°.".to_string(),
+            format!(
+                "{}/{} av 360° är {} × 360° / {} = {}° / {}",
+                self.nominator,
+                self.denominator,
+                self.nominator,
+                self.denominator,
+                self.nominator * 360,
+                self.denominator
+            ),
+            format!(
+                "{}°",
+                self.answer.equation(
+                    &format!("{} / {}", self.nominator * 360, self.denominator),
+                    (self.nominator * 360) as f64 / self.denominator as f64,
+                )
+            ),
+        ]
+    }
+}
@@ -0,0 +1,113 @@
+use rand::Rng;
+
+use crate::{
+    answer::{Answer, Tolerance},
+    quiz::{Difficulty, Question},
+};
+
+// Count and size of the numbers per difficulty. Hard has longer lists to sort.
+fn ranges(difficulty: Difficulty) -> ((u32, u32), (i32, i32)) {
+    match difficulty {
+        Difficulty::Easy => ((3, 4), (0, 10)),
+        Difficulty::Normal => ((4, 6), (0, 20)),
+        Difficulty::Hard => ((7, 10), (0, 100)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MedianQuestion {
+    numbers: Vec<i32>,
+    answer: Answer,
+}
+
+impl MedianQuestion {
+    pub fn new(numbers: Vec<i32>) -> Self {
+        let median: f64 = {
+            let mut sorted_numbers = numbers.clone();
+            sorted_numbers.sort_unstable();
+            let len = sorted_numbers.len();
+            if len.is_multiple_of(2) {
+                (sorted_numbers[len / 2 - 1] + sorted_numbers[len / 2]) as f64 / 2.0
+            } else {
+                sorted_numbers[len / 2] as f64
+            }
+        };
+        Self {
+            numbers,
+            answer: Answer::decimal(median, Tolerance::decimals(1)),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (numbers_count_range, number_value_range) = ranges(difficulty);
+        loop {
+            let number_count: u32 = rng.random_range(numbers_count_range.0..numbers_count_range.1);
+            let numbers: Vec<i32> = (0..number_count)
+                .map(|_| rng.random_range(number_value_range.0..number_value_range.1))
+                .collect();
+
+            let mut sorted_numbers = numbers.clone();
+            sorted_numbers.sort_unstable();
+            let len = sorted_numbers.len();
+
+            let integer_median = if len.is_multiple_of(2) {
+                // For even count, check if the two middle numbers sum to an even number
+                let middle_sum = sorted_numbers[len / 2 - 1] + sorted_numbers[len / 2];
+                if middle_sum % 2 == 0 {
+                    Some(middle_sum / 2)
+                } else {
+                    None
+                }
+            } else {
+                // For odd count, median is always an integer (the middle element)
+                Some(sorted_numbers[len / 2])
+            };
+
+            if let Some(median_value) = integer_median {
+                return Self {
+                    numbers,
+                    answer: Answer::Integer(median_value.into()),
+                };
+            }
+        }
+    }
+}
+
+impl Question for MedianQuestion {
+    fn prompt(&self) -> String {
+        format!("Beräkna medianen: {:?}", self.numbers)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let mut sorted_numbers = self.numbers.clone();
+        sorted_numbers.sort_unstable();
+        let len = sorted_numbers.len();
+        let mut steps = vec![format!(
+            "Sortera talen i storleksordning: {:?}",
+            sorted_numbers
+        )];
+        if len.is_multiple_of(2) {
+            let (lower, upper) = (sorted_numbers[len / 2 - 1], sorted_numbers[len / 2]);
+            steps.push(format!(
+                "Det finns {} tal, ett jämnt antal, så medianen är medelvärdet av de två mittersta talen.",
+                len
+            ));
+            steps.push(self.answer.equation(
+                &format!("({} + {}) / 2", lower, upper),
+                (lower + upper) as f64 / 2.0,
+            ));
+        } else {
+            steps.push(format!(
+                "Det finns {} tal, ett udda antal, så medianen är talet i mitten på plats {}: {}",
+                len,
+                len / 2 + 1,
+                self.answer
+            ));
+        }
+        steps
+    }
+}
@@ -0,0 +1,391 @@
+use std::fmt;
+
+use crate::{
+    answer::Answer,
+    number::format_number,
+    quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView},
+};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+// Largest number in the questions with one blank, per difficulty. Factors and
+// divisors stay within the multiplication table.
+fn max_number(difficulty: Difficulty) -> i64 {
+    match difficulty {
+        Difficulty::Easy => 10,
+        Difficulty::Normal | Difficulty::Hard => 20,
+    }
+}
+
+// How many choices a question with two blanks has
+const CHOICES_WITH_TWO_BLANKS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+const OPERATORS: [Operator; 4] = [
+    Operator::Add,
+    Operator::Subtract,
+    Operator::Multiply,
+    Operator::Divide,
+];
+
+impl Operator {
+    fn apply(self, left: f64, right: f64) -> f64 {
+        match self {
+            Operator::Add => left + right,
+            Operator::Subtract => left - right,
+            Operator::Multiply => left * right,
+            Operator::Divide => left / right,
+        }
+    }
+
+    // × and ÷ are calculated before + and −
+    fn binds_tighter(self) -> bool {
+        matches!(self, Operator::Multiply | Operator::Divide)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Subtract => write!(f, "−"),
+            Operator::Multiply => write!(f, "×"),
+            Operator::Divide => write!(f, "÷"),
+        }
+    }
+}
+
+// The value of the numbers joined by the operators, with × and ÷ first. Division
+// by zero gives a value that doesn't equal anything.
+fn evaluate(numbers: &[i64], operators: &[Operator]) -> f64 {
+    let mut terms = vec![numbers[0] as f64];
+    for (operator, number) in operators.iter().zip(&numbers[1..]) {
+        let number = *number as f64;
+        match operator {
+            Operator::Add => terms.push(number),
+            Operator::Subtract => terms.push(-number),
+            Operator::Multiply | Operator::Divide => {
+                if let Some(last) = terms.last_mut() {
+                    *last = operator.apply(*last, number);
+                }
+            }
+        }
+    }
+    terms.iter().sum()
+}
+
+// Compares a calculated value with a whole number, allowing for floating point
+// noise from the divisions
+fn is_equal(value: f64, number: i64) -> bool {
+    (value - number as f64).abs() < 1e-9
+}
+
+fn written(numbers: &[i64], operators: &[&str]) -> String {
+    let mut text = numbers[0].to_string();
+    for (operator, number) in operators.iter().zip(&numbers[1..]) {
+        text.push_str(&format!(" {} {}", operator, number));
+    }
+    text
+}
+
+// Shows an arithmetic expression `A _ B = C`, or `A _ B _ C = D` on Hard, and
+// the student picks the operators that make it true
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingOperatorQuestion {
+    numbers: Vec<i64>,
+    result: i64,
+    // One operator per blank in each choice
+    choices: Vec<Vec<Operator>>,
+}
+
+impl MissingOperatorQuestion {
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        if difficulty == Difficulty::Hard {
+            return Self::random_two_blanks(rng);
+        }
+
+        let max = max_number(difficulty);
+        // Some numbers work with more than one operator, e.g. 2 + 2 = 2 × 2, so
+        // draw again until exactly one fits
+        loop {
+            let operator = OPERATORS[rng.random_range(0..OPERATORS.len())];
+            let (left, right) = match operator {
+                Operator::Add => (rng.random_range(1..=max), rng.random_range(1..=max)),
+                Operator::Subtract => {
+                    let left = rng.random_range(2..=max);
+                    (left, rng.random_range(1..left))
+                }
+                Operator::Multiply => (rng.random_range(2..=max), rng.random_range(2..=10)),
+                Operator::Divide => {
+                    let right = rng.random_range(2..=10);
+                    (right * rng.random_range(2..=max.min(10)), right)
+                }
+            };
+            let question = Self {
+                numbers: vec![left, right],
+                result: operator.apply(left as f64, right as f64) as i64,
+                choices: OPERATORS.iter().map(|operator| vec![*operator]).collect(),
+            };
+            if question.correct_indices().len() == 1 {
+                return question;
+            }
+        }
+    }
+
+    // Addition or subtraction followed by multiplication or division, so that
+    // calculating from left to right gives the wrong value
+    fn random_two_blanks(rng: &mut impl Rng) -> Self {
+        loop {
+            let first = [Operator::Add, Operator::Subtract][rng.random_range(0..2)];
+            let second = [Operator::Multiply, Operator::Divide][rng.random_range(0..2)];
+            let (middle, last) = match second {
+                Operator::Divide => {
+                    let last = rng.random_range(2..=10);
+                    (last * rng.random_range(1..=10), last)
+                }
+                _ => (rng.random_range(2..=10), rng.random_range(2..=10)),
+            };
+            let numbers = vec![rng.random_range(1..=20), middle, last];
+            let value = evaluate(&numbers, &[first, second]);
+            if value < 0.0 {
+                continue;
+            }
+            let result = value as i64;
+
+            // Every assignment that makes it true is among the choices, filled up
+            // with wrong ones
+            let (mut choices, mut wrong): (Vec<Vec<Operator>>, Vec<Vec<Operator>>) = OPERATORS
+                .iter()
+                .flat_map(|first| OPERATORS.iter().map(move |second| vec![*first, *second]))
+                .partition(|operators| is_equal(evaluate(&numbers, operators), result));
+            if choices.len() > CHOICES_WITH_TWO_BLANKS / 2 {
+                continue;
+            }
+            wrong.shuffle(rng);
+            choices.extend(
+                wrong
+                    .into_iter()
+                    .take(CHOICES_WITH_TWO_BLANKS - choices.len()),
+            );
+            choices.shuffle(rng);
+
+            return Self {
+                numbers,
+                result,
+                choices,
+            };
+        }
+    }
+
+    fn correct_indices(&self) -> Vec<usize> {
+        self.choices
+            .iter()
+            .enumerate()
+            .filter(|(_, operators)| is_equal(evaluate(&self.numbers, operators), self.result))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn choice_text(operators: &[Operator]) -> String {
+        let symbols: Vec<String> = operators
+            .iter()
+            .map(|operator| operator.to_string())
+            .collect();
+        symbols.join(" ")
+    }
+
+    // The calculation with the operators filled in, one step per operator
+    fn worked(&self, operators: &[Operator]) -> String {
+        let symbols: Vec<String> = operators
+            .iter()
+            .map(|operator| operator.to_string())
+            .collect();
+        let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        let expression = written(&self.numbers, &symbols);
+        match (operators, self.numbers.as_slice()) {
+            ([first, second], [a, b, c]) => {
+                let step = if second.binds_tighter() && !first.binds_tighter() {
+                    format!(
+                        "{} {} {}",
+                        a,
+                        first,
+                        format_number(second.apply(*b as f64, *c as f64))
+                    )
+                } else {
+                    format!(
+                        "{} {} {}",
+                        format_number(first.apply(*a as f64, *b as f64)),
+                        second,
+                        c
+                    )
+                };
+                format!("{} = {} = {}", expression, step, self.result)
+            }
+            _ => format!("{} = {}", expression, self.result),
+        }
+    }
+}
+
+impl Question for MissingOperatorQuestion {
+    fn prompt(&self) -> String {
+        let blanks = vec!["_"; self.numbers.len() - 1];
+        let question = if blanks.len() == 1 {
+            "Vilket räknesätt saknas?"
+        } else {
+            "Vilka räknesätt saknas?"
+        };
+        format!(
+            "{} {} = {}",
+            question,
+            written(&self.numbers, &blanks),
+            self.result
+        )
+    }
+
+    fn answer(&self) -> Answer {
+        let indices = self.correct_indices();
+        let texts: Vec<String> = indices
+            .iter()
+            .map(|index| Self::choice_text(&self.choices[*index]))
+            .collect();
+        let label = texts.join(" eller ");
+        match indices.as_slice() {
+            [index] => Answer::Choice {
+                index: *index,
+                label,
+            },
+            _ => Answer::AnyChoice { indices, label },
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let correct = self.correct_indices();
+        if self.numbers.len() == 2 {
+            let (left, right) = (self.numbers[0], self.numbers[1]);
+            let tries: Vec<String> = OPERATORS
+                .iter()
+                .map(|operator| {
+                    let value = operator.apply(left as f64, right as f64);
+                    if value.fract() == 0.0 {
+                        format!("{} {} {} = {}", left, operator, right, value)
+                    } else {
+                        format!("{} {} {} är inte ett heltal", left, operator, right)
+                    }
+                })
+                .collect();
+            return vec![
+                format!("Prova alla räknesätt: {}", tries.join(", ")),
+                format!("Bara {} ger {}.", self.answer(), self.result),
+            ];
+        }
+
+        let mut steps =
+            vec!["Multiplikation och division räknas före addition och subtraktion.".to_string()];
+        steps.extend(
+            correct
+                .iter()
+                .map(|index| self.worked(&self.choices[*index])),
+        );
+        steps
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Choices(self.get_choices())
+    }
+}
+
+impl MultipleChoiceQuestionProvider for MissingOperatorQuestion {
+    fn get_choices(&self) -> Vec<Choice> {
+        self.choices
+            .iter()
+            .enumerate()
+            .map(|(index, operators)| Choice {
+                display_text: Self::choice_text(operators),
+                value: index.to_string(), // The value to check against the answer
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn one_blank_has_exactly_one_right_operator() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for difficulty in [Difficulty::Easy, Difficulty::Normal] {
+            for _ in 0..500 {
+                let question = MissingOperatorQuestion::random(&mut rng, difficulty);
+                let (left, right) = (question.numbers[0], question.numbers[1]);
+                let fitting: Vec<usize> = OPERATORS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, operator)| match operator {
+                        Operator::Add => left + right == question.result,
+                        Operator::Subtract => left - right == question.result,
+                        Operator::Multiply => left * right == question.result,
+                        Operator::Divide => right * question.result == left,
+                    })
+                    .map(|(index, _)| index)
+                    .collect();
+                assert_eq!(fitting.len(), 1, "{}", question.prompt());
+                for index in 0..OPERATORS.len() {
+                    assert_eq!(
+                        question.check_answer(&index.to_string()),
+                        fitting.contains(&index),
+                        "{}",
+                        question.prompt()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn two_blanks_follow_the_order_of_operations() {
+        // 2 + 3 × 4 = 14, while 2 × 3 + 4 and 2 + 3 + 4 are 10 and 9
+        let question = MissingOperatorQuestion {
+            numbers: vec![2, 3, 4],
+            result: 14,
+            choices: vec![
+                vec![Operator::Multiply, Operator::Add],
+                vec![Operator::Add, Operator::Multiply],
+                vec![Operator::Add, Operator::Add],
+            ],
+        };
+        assert!(question.check_answer("1"));
+        assert!(!question.check_answer("0"));
+        assert!(!question.check_answer("2"));
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..500 {
+            let question = MissingOperatorQuestion::random(&mut rng, Difficulty::Hard);
+            let [a, b, c] = question.numbers[..] else {
+                panic!("{}", question.prompt());
+            };
+            let mut right_choices = 0;
+            for (index, operators) in question.choices.iter().enumerate() {
+                let (first, second) = (operators[0], operators[1]);
+                let value = if second.binds_tighter() && !first.binds_tighter() {
+                    first.apply(a as f64, second.apply(b as f64, c as f64))
+                } else {
+                    second.apply(first.apply(a as f64, b as f64), c as f64)
+                };
+                let right = is_equal(value, question.result);
+                right_choices += usize::from(right);
+                assert_eq!(question.check_answer(&index.to_string()), right);
+            }
+            assert!(right_choices >= 1, "{}", question.prompt());
+        }
+    }
+}
@@ -16,6 +16,8 @@ pub mod percent_change;
 pub use percent_change::*;
 pub mod expressions;
 pub use expressions::*;
+pub mod order_of_operations;
+pub use order_of_operations::*;
 pub mod fraction_to_degree;
 pub use fraction_to_degree::*;
 pub mod fraction_comparizon;
@@ -36,7 +38,19 @@ pub mod negative_values;
 pub use negative_values::*;
 pub mod clock_reading;
 pub use clock_reading::*;
+pub mod durations;
+pub use durations::*;
 pub mod circumference;
 pub use circumference::*;
 pub mod roman_numerals;
 pub use roman_numerals::*;
+pub mod perimeter;
+pub use perimeter::*;
+pub mod solids;
+pub use solids::*;
+pub mod divisibility;
+pub use divisibility::*;
+pub mod missing_operator;
+pub use missing_operator::*;
+pub mod fraction_arithmetic;
+pub use fraction_arithmetic::*;
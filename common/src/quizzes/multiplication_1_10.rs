@@ -0,0 +1,60 @@
+use crate::{answer::Answer, quiz::*};
+use rand::Rng;
+
+// Range of the factors per difficulty, Hard is the upper half of the table
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 6),
+        Difficulty::Normal => (1, 10),
+        Difficulty::Hard => (6, 10),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiplicationQuestion1_10 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl MultiplicationQuestion1_10 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result = first * second;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        Self::new(
+            rng.random_range(range.0..range.1),
+            rng.random_range(range.0..range.1),
+        )
+    }
+}
+
+impl Question for MultiplicationQuestion1_10 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} × {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        // Multiplication as repeated addition
+        let terms = vec![self.first_number.to_string(); self.second_number as usize];
+        vec![
+            format!(
+                "{} × {} betyder {} grupper med {} i varje.",
+                self.first_number, self.second_number, self.second_number, self.first_number
+            ),
+            format!("{} = {}", terms.join(" + "), self.answer),
+        ]
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{answer::Answer, quiz::*};
+use rand::Rng;
+
+// Range of the factors per difficulty, Hard only uses factors above 10
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 11),
+        Difficulty::Normal => (1, 20),
+        Difficulty::Hard => (11, 20),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultiplicationQuestion1_20 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl MultiplicationQuestion1_20 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result = first * second;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        Self::new(
+            rng.random_range(range.0..range.1),
+            rng.random_range(range.0..range.1),
+        )
+    }
+}
+
+impl Question for MultiplicationQuestion1_20 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} × {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (first, second) = (self.first_number, self.second_number);
+        if first < 10 && second < 10 {
+            let terms = vec![first.to_string(); second as usize];
+            return vec![
+                format!(
+                    "{} × {} betyder {} grupper med {} i varje.",
+                    first, second, second, first
+                ),
+                format!("{} = {}", terms.join(" + "), self.answer),
+            ];
+        }
+
+        // Split the larger factor into tens and ones
+        let (larger, smaller) = (first.max(second), first.min(second));
+        let ones = larger - 10;
+        if ones == 0 {
+            return vec![format!(
+                "Att multiplicera med 10 är att lägga till en nolla: {} × 10 = {}",
+                smaller, self.answer
+            )];
+        }
+        vec![
+            format!(
+                "Dela upp {} i 10 + {} och multiplicera delarna med {} var för sig.",
+                larger, ones, smaller
+            ),
+            format!(
+                "{} × 10 = {} och {} × {} = {}",
+                smaller,
+                smaller * 10,
+                smaller,
+                ones,
+                smaller * ones
+            ),
+            format!("{} + {} = {}", smaller * 10, smaller * ones, self.answer),
+        ]
+    }
+}
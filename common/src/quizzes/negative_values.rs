@@ -0,0 +1,155 @@
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question},
+};
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NegativeValuesQuestion {
+    first_number: i32,
+    second_number: i32,
+    operation: char,
+    answer: Answer,
+}
+
+impl NegativeValuesQuestion {
+    pub fn new(first: i32, second: i32, operation: char) -> Self {
+        let result = match operation {
+            '+' => first + second,
+            '-' => first - second,
+            _ => panic!("Invalid operation: {}", operation),
+        };
+        Self {
+            first_number: first,
+            second_number: second,
+            operation,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        // Largest absolute value of the numbers, e.g. -10 to 10 on Normal
+        let (left_val_range_abs, right_val_range_abs) = match difficulty {
+            Difficulty::Easy => (5, 5),
+            Difficulty::Normal => (10, 10),
+            Difficulty::Hard => (50, 20),
+        };
+
+        // Randomly pick an operation
+        let operation = if rng.random_bool(0.5) { '+' } else { '-' };
+
+        let first: i32;
+        let second: i32;
+
+        match operation {
+            '+' => {
+                // Scenarios for addition with negative numbers
+                match rng.random_range(0..3) {
+                    0 => {
+                        // Positive + Negative
+                        first = rng.random_range(1..=left_val_range_abs);
+                        second = rng.random_range(-right_val_range_abs..=-1);
+                    }
+                    1 => {
+                        // Negative + Positive
+                        first = rng.random_range(-left_val_range_abs..=-1);
+                        second = rng.random_range(1..=right_val_range_abs);
+                    }
+                    _ => {
+                        // Negative + Negative
+                        first = rng.random_range(-left_val_range_abs..=-1);
+                        second = rng.random_range(-right_val_range_abs..=-1);
+                    }
+                }
+            }
+            '-' => {
+                // Scenarios for subtraction involving negative numbers or resulting in negative
+                match rng.random_range(0..4) {
+                    0 => {
+                        // Positive - Negative (e.g., 5 - (-3) = 5 + 3)
+                        first = rng.random_range(1..=left_val_range_abs);
+                        second = rng.random_range(-right_val_range_abs..=-1);
+                    }
+                    1 => {
+                        // Negative - Positive (e.g., -5 - 3 = -8)
+                        first = rng.random_range(-left_val_range_abs..=-1);
+                        second = rng.random_range(1..=right_val_range_abs);
+                    }
+                    2 => {
+                        // Negative - Negative (e.g., -5 - (-3) = -5 + 3 = -2)
+                        first = rng.random_range(-left_val_range_abs..=-1);
+                        second = rng.random_range(-right_val_range_abs..=-1);
+                    }
+                    _ => {
+                        // Positive - Larger Positive (to get a negative result, e.g., 3 - 5 = -2)
+                        second = rng.random_range(2..=right_val_range_abs);
+                        first = rng.random_range(1..second.min(left_val_range_abs));
+                    }
+                }
+            }
+            _ => unreachable!("Invalid operation generated"),
+        }
+        Self::new(first, second, operation)
+    }
+}
+
+impl NegativeValuesQuestion {
+    // Format nicely: "5 + (-3)" or "5 - (-3)"
+    fn prompt_expression(&self) -> String {
+        if self.second_number < 0 {
+            format!(
+                "{} {} ({})",
+                self.first_number, self.operation, self.second_number
+            )
+        } else {
+            format!(
+                "{} {} {}",
+                self.first_number, self.operation, self.second_number
+            )
+        }
+    }
+}
+
+impl Question for NegativeValuesQuestion {
+    fn prompt(&self) -> String {
+        format!("Beräkna {}?", self.prompt_expression())
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (first, second) = (self.first_number, self.second_number);
+        match (self.operation, second < 0) {
+            ('+', true) => vec![
+                "Att addera ett negativt tal är samma sak som att subtrahera.".to_string(),
+                format!(
+                    "{} + ({}) = {} - {} = {}",
+                    first, second, first, -second, self.answer
+                ),
+            ],
+            ('-', true) => vec![
+                "Att subtrahera ett negativt tal är samma sak som att addera.".to_string(),
+                format!(
+                    "{} - ({}) = {} + {} = {}",
+                    first, second, first, -second, self.answer
+                ),
+            ],
+            (operation, _) => {
+                let direction = if operation == '+' {
+                    "höger"
+                } else {
+                    "vänster"
+                };
+                vec![
+                    format!(
+                        "Börja på {} på tallinjen och gå {} steg åt {}.",
+                        first, second, direction
+                    ),
+                    format!("{} = {}", self.prompt_expression(), self.answer),
+                ]
+            }
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::quiz::{Choice, MultipleChoiceQuestionProvider, Question};
+use crate::{
+    answer::Answer,
+    number::format_number,
+    quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView},
+};
 use rand::Rng;
 use std::cmp::Ordering;
 
@@ -32,9 +36,32 @@ impl NumberValue {
         }
     }
 
-    // Generate a random fraction
-    pub fn random_fraction(rng: &mut impl Rng) -> Self {
-        let denominator = rng.random_range(2..=10);
+    // Explains how the value is written as a decimal number
+    fn as_decimal_step(&self) -> String {
+        match self {
+            NumberValue::Fraction {
+                numerator,
+                denominator,
+            } => format!(
+                "{}/{} = {} / {} ≈ {}",
+                numerator,
+                denominator,
+                numerator,
+                denominator,
+                format_number(self.as_f64())
+            ),
+            NumberValue::Decimal(_) => format!("{} är redan ett decimaltal.", self.display()),
+        }
+    }
+
+    // Generate a random fraction. Harder levels use larger denominators.
+    pub fn random_fraction(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max_denominator = match difficulty {
+            Difficulty::Easy => 5,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 20,
+        };
+        let denominator = rng.random_range(2..=max_denominator);
         let numerator = rng.random_range(1..denominator);
 
         // Try to keep the fraction in simplest form
@@ -49,18 +76,41 @@ impl NumberValue {
     }
 
     // Generate a random decimal
-    pub fn random_decimal(rng: &mut impl Rng) -> Self {
-        // Generate a number between 0.1 and 0.9 with one decimal place
-        let decimal = (rng.random_range(1..10) as f64) / 10.0;
+    pub fn random_decimal(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let decimal = match difficulty {
+            // A number between 0.01 and 0.99 with two decimal places
+            Difficulty::Hard => (rng.random_range(1..100) as f64) / 100.0,
+            // A number between 0.1 and 0.9 with one decimal place
+            _ => (rng.random_range(1..10) as f64) / 10.0,
+        };
         NumberValue::Decimal(decimal)
     }
 }
 
 // Helper function to find greatest common divisor (for simplifying fractions)
-fn gcd(a: i32, b: i32) -> i32 {
+pub(crate) fn gcd(a: i32, b: i32) -> i32 {
     if b == 0 { a } else { gcd(b, a % b) }
 }
 
+// The last step of a comparison: which value is larger, and where it was shown
+pub(crate) fn comparison_step(
+    first: &NumberValue,
+    second: &NumberValue,
+    answer: &Answer,
+) -> String {
+    let (larger, smaller) = if first.as_f64() > second.as_f64() {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    format!(
+        "{} är större än {}, så rätt svar är {}.",
+        larger.display(),
+        smaller.display(),
+        answer
+    )
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct NumberComparisonQuestion {
     first_value: NumberValue,
@@ -86,27 +136,25 @@ impl NumberComparisonQuestion {
         }
     }
 
-    pub fn random() -> Self {
-        let mut rng = rand::rng();
-
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
         let (first, second) =
             // Mix fraction and decimal
             if rng.random_bool(0.5) {
                 (
-                    NumberValue::random_fraction(&mut rng),
-                    NumberValue::random_decimal(&mut rng),
+                    NumberValue::random_fraction(rng, difficulty),
+                    NumberValue::random_decimal(rng, difficulty),
                 )
             } else {
                 (
-                    NumberValue::random_decimal(&mut rng),
-                    NumberValue::random_fraction(&mut rng),
+                    NumberValue::random_decimal(rng, difficulty),
+                    NumberValue::random_fraction(rng, difficulty),
                 )
             };
 
         // Ensure they're not equal
         if (first.as_f64() - second.as_f64()).abs() < 0.0001 {
             // If they're too close, just return a new random one
-            return Self::random();
+            return Self::random(rng, difficulty);
         }
 
         Self::new(first, second)
@@ -130,29 +178,35 @@ impl Question for NumberComparisonQuestion {
         "Vilket värde är störst".to_string()
     }
 
-    fn answer(&self) -> &str {
-        match self.correct_answer {
+    fn answer(&self) -> Answer {
+        let label = match self.correct_answer {
             0 => "vänster",
             1 => "höger",
             _ => "lika", // This shouldn't happen as we ensure they're not equal
+        };
+        Answer::Choice {
+            index: self.correct_answer,
+            label: label.to_string(),
         }
     }
 
-    // Override check_answer to handle our custom click behavior
-    fn check_answer(&self, answer: &str) -> bool {
-        match answer {
-            "0" => self.correct_answer == 0,
-            "1" => self.correct_answer == 1,
-            // If you ever have an "equal" option as a third button for this type:
-            // "2" => self.correct_answer == 2,
-            _ => false,
-        }
+    fn explanation(&self) -> Vec<String> {
+        vec![
+            "Skriv båda talen som decimaltal så att de går att jämföra.".to_string(),
+            self.first_value.as_decimal_step(),
+            self.second_value.as_decimal_step(),
+            comparison_step(&self.first_value, &self.second_value, &self.answer()),
+        ]
     }
 
     // Override display to customize the output
     fn display(&self) -> String {
         "Vilket värde är störst?".to_string()
     }
+
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Choices(self.get_choices())
+    }
 }
 
 impl MultipleChoiceQuestionProvider for NumberComparisonQuestion {
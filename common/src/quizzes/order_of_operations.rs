@@ -0,0 +1,575 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question},
+    quizzes::Operator,
+};
+use rand::Rng;
+
+// Number of operators, largest number, largest value along the way, and whether
+// powers and negative values are used, per difficulty
+struct Settings {
+    operators: usize,
+    max_number: i64,
+    max_value: i64,
+    powers: bool,
+    negative: bool,
+}
+
+fn settings(difficulty: Difficulty) -> Settings {
+    match difficulty {
+        Difficulty::Easy => Settings {
+            operators: 2,
+            max_number: 10,
+            max_value: 100,
+            powers: false,
+            negative: false,
+        },
+        Difficulty::Normal => Settings {
+            operators: 3,
+            max_number: 10,
+            max_value: 200,
+            powers: true,
+            negative: false,
+        },
+        Difficulty::Hard => Settings {
+            operators: 4,
+            max_number: 12,
+            max_value: 1000,
+            powers: true,
+            negative: true,
+        },
+    }
+}
+
+const OPERATORS: [Operator; 4] = [
+    Operator::Add,
+    Operator::Subtract,
+    Operator::Multiply,
+    Operator::Divide,
+];
+
+// How hard each kind of part binds. A part is written in parentheses when it
+// binds looser than where it stands.
+const NEGATIVE_PRECEDENCE: u8 = 0;
+const POWER_PRECEDENCE: u8 = 3;
+const NUMBER_PRECEDENCE: u8 = 4;
+
+fn operator_precedence(operator: Operator) -> u8 {
+    match operator {
+        Operator::Add | Operator::Subtract => 1,
+        Operator::Multiply | Operator::Divide => 2,
+    }
+}
+
+fn apply(operator: Operator, left: i64, right: i64) -> Option<i64> {
+    match operator {
+        Operator::Add => left.checked_add(right),
+        Operator::Subtract => left.checked_sub(right),
+        Operator::Multiply => left.checked_mul(right),
+        Operator::Divide => (right != 0 && left % right == 0).then(|| left / right),
+    }
+}
+
+fn superscript(exponent: u32) -> &'static str {
+    match exponent {
+        2 => "²",
+        _ => "³",
+    }
+}
+
+// Which operand of a part, on the way down to another part
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Side {
+    Left,
+    Right,
+}
+
+// The kinds of calculation, in the order they are done outside of parentheses
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Step {
+    Power,
+    MultiplyOrDivide,
+    AddOrSubtract,
+}
+
+// A part that can be calculated right away, found by its path from the top
+struct Candidate {
+    path: Vec<Side>,
+    // How many pairs of parentheses it is inside
+    depth: usize,
+    step: Step,
+}
+
+// An arithmetic expression as a tree, e.g. 3 + 4 × (2 − 5)²
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Number(i64),
+    Binary {
+        operator: Operator,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+    Power {
+        base: Box<Expr>,
+        exponent: u32,
+    },
+}
+
+impl Expr {
+    // Any mix of the four operators, with the numbers 1 to `max_number`
+    fn random(rng: &mut impl Rng, operators: usize, max_number: i64) -> Self {
+        if operators == 0 {
+            return Expr::Number(rng.random_range(1..=max_number));
+        }
+        let left_operators = rng.random_range(0..operators);
+        Expr::Binary {
+            operator: OPERATORS[rng.random_range(0..OPERATORS.len())],
+            left: Box::new(Self::random(rng, left_operators, max_number)),
+            right: Box::new(Self::random(
+                rng,
+                operators - 1 - left_operators,
+                max_number,
+            )),
+        }
+    }
+
+    fn operator_count(&self) -> usize {
+        match self {
+            Expr::Number(_) => 0,
+            Expr::Binary { left, right, .. } => 1 + left.operator_count() + right.operator_count(),
+            Expr::Power { base, .. } => base.operator_count(),
+        }
+    }
+
+    // The parts with at most one operator, which can be squared or cubed
+    fn power_bases(&self) -> usize {
+        let own = usize::from(self.operator_count() <= 1);
+        match self {
+            Expr::Binary { left, right, .. } => own + left.power_bases() + right.power_bases(),
+            _ => own,
+        }
+    }
+
+    // Raises the part with at most one operator at `index`, counting from the
+    // left as in `power_bases`
+    fn raised(self, index: &mut usize, exponent: u32) -> Self {
+        if self.operator_count() <= 1 {
+            if *index == 0 {
+                *index = usize::MAX;
+                return Expr::Power {
+                    base: Box::new(self),
+                    exponent,
+                };
+            }
+            *index -= 1;
+        }
+        match self {
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left = left.raised(index, exponent);
+                let right = right.raised(index, exponent);
+                Expr::Binary {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+            other => other,
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Number(value) if *value < 0 => NEGATIVE_PRECEDENCE,
+            Expr::Number(_) => NUMBER_PRECEDENCE,
+            Expr::Binary { operator, .. } => operator_precedence(*operator),
+            Expr::Power { .. } => POWER_PRECEDENCE,
+        }
+    }
+
+    fn child(&self, side: Side) -> Option<&Expr> {
+        match (self, side) {
+            (Expr::Binary { left, .. }, Side::Left) => Some(left),
+            (Expr::Binary { right, .. }, Side::Right) => Some(right),
+            (Expr::Power { base, .. }, Side::Left) => Some(base),
+            _ => None,
+        }
+    }
+
+    // Whether the operand on `side` is written in parentheses. An operand on the
+    // right keeps them even when it binds as hard, e.g. 8 − (3 + 2), so that the
+    // expression is calculated from left to right as written. A negative number
+    // first needs none, e.g. −3 + 4.
+    fn parenthesized(&self, side: Side) -> bool {
+        let Some(child) = self.child(side) else {
+            return false;
+        };
+        match (self, side) {
+            (Expr::Binary { .. }, Side::Left) if matches!(child, Expr::Number(_)) => false,
+            (Expr::Binary { operator, .. }, Side::Left) => {
+                child.precedence() < operator_precedence(*operator)
+            }
+            (Expr::Binary { operator, .. }, Side::Right) => {
+                child.precedence() <= operator_precedence(*operator)
+            }
+            _ => child.precedence() <= POWER_PRECEDENCE,
+        }
+    }
+
+    fn has_parentheses(&self) -> bool {
+        [Side::Left, Side::Right].into_iter().any(|side| {
+            self.parenthesized(side) || self.child(side).is_some_and(Expr::has_parentheses)
+        })
+    }
+
+    // Whether the order of operations changes the value compared to calculating
+    // from left to right, because of a power or operators of different precedence
+    fn mixes_precedence(&self) -> bool {
+        match self {
+            Expr::Number(_) => false,
+            Expr::Power { .. } => true,
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => [left, right].into_iter().any(|child| {
+                child.mixes_precedence()
+                    || matches!(child.as_ref(), Expr::Binary { operator: inner, .. }
+                        if operator_precedence(*inner) != operator_precedence(*operator))
+            }),
+        }
+    }
+
+    fn has_add_or_subtract(&self) -> bool {
+        match self {
+            Expr::Number(_) => false,
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                matches!(operator, Operator::Add | Operator::Subtract)
+                    || left.has_add_or_subtract()
+                    || right.has_add_or_subtract()
+            }
+            Expr::Power { base, .. } => base.has_add_or_subtract(),
+        }
+    }
+
+    // The value, as long as every division comes out even and no value along the
+    // way is larger than `max_value` or, unless allowed, negative
+    fn evaluate(&self, max_value: i64, negative: bool) -> Option<i64> {
+        let value = match self {
+            Expr::Number(value) => *value,
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => apply(
+                *operator,
+                left.evaluate(max_value, negative)?,
+                right.evaluate(max_value, negative)?,
+            )?,
+            Expr::Power { base, exponent } => {
+                base.evaluate(max_value, negative)?.checked_pow(*exponent)?
+            }
+        };
+        (value.abs() <= max_value && (negative || value >= 0)).then_some(value)
+    }
+
+    // The parts whose operands are all numbers, from left to right
+    fn candidates(&self, path: &mut Vec<Side>, depth: usize, found: &mut Vec<Candidate>) {
+        let step = match self {
+            Expr::Number(_) => return,
+            Expr::Power { .. } => Step::Power,
+            Expr::Binary {
+                operator: Operator::Multiply | Operator::Divide,
+                ..
+            } => Step::MultiplyOrDivide,
+            Expr::Binary { .. } => Step::AddOrSubtract,
+        };
+        let sides: &[Side] = match self {
+            Expr::Power { .. } => &[Side::Left],
+            _ => &[Side::Left, Side::Right],
+        };
+        if sides
+            .iter()
+            .all(|side| matches!(self.child(*side), Some(Expr::Number(_))))
+        {
+            found.push(Candidate {
+                path: path.clone(),
+                depth,
+                step,
+            });
+            return;
+        }
+        for side in sides {
+            if let Some(child) = self.child(*side) {
+                path.push(*side);
+                let depth = depth + usize::from(self.parenthesized(*side));
+                child.candidates(path, depth, found);
+                path.pop();
+            }
+        }
+    }
+
+    // What to calculate next: the innermost parentheses first, then powers, then
+    // × and ÷, and last + and −, each from left to right
+    fn next_step(&self) -> Option<Candidate> {
+        let mut found = Vec::new();
+        self.candidates(&mut Vec::new(), 0, &mut found);
+        found
+            .into_iter()
+            .min_by_key(|candidate| (Reverse(candidate.depth), candidate.step))
+    }
+
+    fn at(&self, path: &[Side]) -> &Expr {
+        match path.split_first() {
+            Some((side, rest)) => self.child(*side).map_or(self, |child| child.at(rest)),
+            None => self,
+        }
+    }
+
+    fn replaced(&self, path: &[Side], value: i64) -> Expr {
+        let Some((side, rest)) = path.split_first() else {
+            return Expr::Number(value);
+        };
+        match self {
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let (left, right) = match side {
+                    Side::Left => (left.replaced(rest, value), right.as_ref().clone()),
+                    Side::Right => (left.as_ref().clone(), right.replaced(rest, value)),
+                };
+                Expr::Binary {
+                    operator: *operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                }
+            }
+            Expr::Power { base, exponent } => Expr::Power {
+                base: Box::new(base.replaced(rest, value)),
+                exponent: *exponent,
+            },
+            Expr::Number(_) => self.clone(),
+        }
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, side: Side) -> fmt::Result {
+        match self.child(side) {
+            Some(child) if self.parenthesized(side) => write!(f, "({})", child),
+            Some(child) => write!(f, "{}", child),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(value) if *value < 0 => write!(f, "−{}", -value),
+            Expr::Number(value) => write!(f, "{}", value),
+            Expr::Binary { operator, .. } => {
+                self.write_operand(f, Side::Left)?;
+                write!(f, " {} ", operator)?;
+                self.write_operand(f, Side::Right)
+            }
+            Expr::Power { exponent, .. } => {
+                self.write_operand(f, Side::Left)?;
+                write!(f, "{}", superscript(*exponent))
+            }
+        }
+    }
+}
+
+// Calculating an expression with parentheses, powers and all four operators,
+// where the order of operations matters. Easy has no parentheses or powers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderOfOperationsQuestion {
+    expression: Expr,
+    value: i64,
+}
+
+impl OrderOfOperationsQuestion {
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let settings = settings(difficulty);
+        loop {
+            let mut expression = Expr::random(rng, settings.operators, settings.max_number);
+            if settings.powers && rng.random_bool(0.5) {
+                let mut index = rng.random_range(0..expression.power_bases());
+                let exponent = if difficulty == Difficulty::Hard && rng.random_bool(0.3) {
+                    3
+                } else {
+                    2
+                };
+                expression = expression.raised(&mut index, exponent);
+            }
+            if !expression.mixes_precedence()
+                || expression.has_parentheses() == (difficulty == Difficulty::Easy)
+            {
+                continue;
+            }
+            if let Some(value) = expression.evaluate(settings.max_value, settings.negative) {
+                return Self { expression, value };
+            }
+        }
+    }
+}
+
+impl Question for OrderOfOperationsQuestion {
+    fn prompt(&self) -> String {
+        format!("Beräkna {}", self.expression)
+    }
+
+    fn answer(&self) -> Answer {
+        Answer::Integer(self.value)
+    }
+
+    // One calculation per step, with the whole expression after it
+    fn explanation(&self) -> Vec<String> {
+        let mut steps = Vec::new();
+        let mut expression = self.expression.clone();
+        while let Some(candidate) = expression.next_step() {
+            let part = expression.at(&candidate.path);
+            let Some(value) = part.evaluate(i64::MAX, true) else {
+                break;
+            };
+            let rule = match candidate.step {
+                _ if candidate.depth > 0 => "Parentesen först",
+                Step::Power => "Potensen före de fyra räknesätten",
+                Step::MultiplyOrDivide if expression.has_add_or_subtract() => {
+                    "Multiplikation och division före addition och subtraktion"
+                }
+                Step::MultiplyOrDivide | Step::AddOrSubtract => "Från vänster till höger",
+            };
+            let next = expression.replaced(&candidate.path, value);
+            steps.push(if next == Expr::Number(value) {
+                format!("Till sist: {} = {}", part, next)
+            } else {
+                format!(
+                    "{}: {} = {}, så {} = {}",
+                    rule,
+                    part,
+                    Expr::Number(value),
+                    expression,
+                    next
+                )
+            });
+            expression = next;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn number(value: i64) -> Expr {
+        Expr::Number(value)
+    }
+
+    fn binary(operator: Operator, left: Expr, right: Expr) -> Expr {
+        Expr::Binary {
+            operator,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    #[test]
+    fn multiplies_before_adding() {
+        let expression = binary(
+            Operator::Add,
+            number(3),
+            binary(Operator::Multiply, number(4), number(2)),
+        );
+        assert_eq!(expression.to_string(), "3 + 4 × 2");
+        assert_eq!(expression.evaluate(100, false), Some(11));
+
+        let question = OrderOfOperationsQuestion {
+            expression,
+            value: 11,
+        };
+        assert_eq!(
+            question.explanation(),
+            [
+                "Multiplikation och division före addition och subtraktion: 4 × 2 = 8, så 3 + 4 × 2 = 3 + 8",
+                "Till sist: 3 + 8 = 11",
+            ]
+        );
+
+        let grouped = binary(
+            Operator::Multiply,
+            binary(Operator::Add, number(3), number(4)),
+            number(2),
+        );
+        assert_eq!(grouped.to_string(), "(3 + 4) × 2");
+        assert_eq!(grouped.evaluate(100, false), Some(14));
+    }
+
+    #[test]
+    fn writes_parentheses_only_where_needed() {
+        let right = binary(
+            Operator::Subtract,
+            number(8),
+            binary(Operator::Add, number(3), number(2)),
+        );
+        assert_eq!(right.to_string(), "8 − (3 + 2)");
+        assert_eq!(right.evaluate(100, false), Some(3));
+
+        let left = binary(
+            Operator::Add,
+            binary(Operator::Subtract, number(8), number(3)),
+            number(2),
+        );
+        assert_eq!(left.to_string(), "8 − 3 + 2");
+
+        let power = Expr::Power {
+            base: Box::new(binary(Operator::Subtract, number(2), number(5))),
+            exponent: 2,
+        };
+        assert_eq!(power.to_string(), "(2 − 5)²");
+        assert_eq!(power.evaluate(100, true), Some(9));
+        assert_eq!(power.evaluate(100, false), None);
+
+        assert_eq!(
+            binary(Operator::Add, number(-3), number(4)).to_string(),
+            "−3 + 4"
+        );
+        assert_eq!(
+            binary(Operator::Divide, number(72), number(-6)).to_string(),
+            "72 ÷ (−6)"
+        );
+    }
+
+    #[test]
+    fn explanation_ends_with_the_answer() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            for _ in 0..200 {
+                let question = OrderOfOperationsQuestion::random(&mut rng, difficulty);
+                let steps = question.explanation();
+                let last = steps.last().expect("at least one step");
+                assert!(last.starts_with("Till sist: "), "{}", last);
+                assert!(
+                    last.ends_with(&format!("= {}", Expr::Number(question.value))),
+                    "{} {:?}",
+                    question.prompt(),
+                    steps
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    answer::{Answer, Tolerance},
+    number::format_number,
+    quiz::{Difficulty, Question},
+};
+use rand::Rng;
+
+static ITEMS: [&str; 6] = [
+    "Donald Duck comic book",
+    "Nintendo Switch 2",
+    "Tesla Cybertruck",
+    "iPhone 16",
+    "PlayStation 5",
+    "Minecraft account",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PercentChangeQuestion {
+    current_price: i32,
+    percentage_change: i32,
+    item: String,
+    answer: Answer,
+}
+
+impl PercentChangeQuestion {
+    pub fn new(current_price: i32, percent_change: i32, item: &str) -> Self {
+        let change: f64 = current_price as f64 * percent_change as f64 / 100.0;
+        let new_price: f64 = current_price as f64 + change;
+        let answer = Answer::decimal(new_price, Tolerance::decimals(1));
+
+        Self {
+            current_price,
+            item: item.to_string(),
+            percentage_change: percent_change,
+            answer,
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let (current_price, percentage_change) = match difficulty {
+            // Whole tens of kronor and percent
+            Difficulty::Easy => (
+                rng.random_range(1..=20) * 10,
+                rng.random_range(-5..=10) * 10,
+            ),
+            Difficulty::Normal => (rng.random_range(1..1000), rng.random_range(-50..250)),
+            Difficulty::Hard => (rng.random_range(1..10000), rng.random_range(-90..500)),
+        };
+        Self::new(current_price, percentage_change, random_item(rng))
+    }
+}
+
+impl Question for PercentChangeQuestion {
+    fn prompt(&self) -> String {
+        match self.percentage_change {
+            change if change > 0 => format!(
+                "{} kostar {}kr. Priset höjs med {}%. Beräkna det nya priset.",
+                self.item, self.current_price, self.percentage_change
+            ),
+            change if change < 0 => format!(
+                "{} kostar {}kr. Priset sänks med {}%. Beräkna det nya priset.",
+                self.item, self.current_price, -self.percentage_change
+            ),
+            _ => format!(
+                "Vad är det nya priset på {} efter prisförändring på 0%?",
+                self.item
+            ),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let price = self.current_price;
+        let percent = self.percentage_change;
+        let change = price as f64 * percent.abs() as f64 / 100.0;
+        let new_price = price as f64 + price as f64 * percent as f64 / 100.0;
+        if percent == 0 {
+            return vec![format!(
+                "Priset ändras inte, så det är fortfarande {} kr.",
+                self.answer
+            )];
+        }
+        let (verb, operator) = if percent > 0 {
+            ("höjs", '+')
+        } else {
+            ("sänks", '-')
+        };
+        vec![
+            format!(
+                "{}% av {} kr är {} × {} / 100 = {} kr.",
+                percent.abs(),
+                price,
+                price,
+                percent.abs(),
+                format_number(change)
+            ),
+            format!("Priset {} med {} kr.", verb, format_number(change)),
+            format!(
+                "{} kr",
+                self.answer.equation(
+                    &format!("{} {} {}", price, operator, format_number(change)),
+                    new_price
+                )
+            ),
+        ]
+    }
+}
+
+fn random_item(rng: &mut impl Rng) -> &'static str {
+    let index: usize = rng.random_range(0..ITEMS.len());
+    ITEMS[index]
+}
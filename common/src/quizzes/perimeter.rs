@@ -0,0 +1,390 @@
+use core::f64;
+
+use rand::Rng;
+
+use crate::{
+    answer::Answer,
+    figure::Figure,
+    quiz::{Difficulty, Question, QuestionView},
+    quizzes::ShapeType,
+};
+
+// Longest side of the figures, and the shapes drawn, per difficulty. Easy has
+// the plain four-sided shapes and triangles, Normal adds regular polygons and
+// L-shapes, and Hard leaves out the inner sides of the L-shapes.
+fn max_side(difficulty: Difficulty) -> u32 {
+    match difficulty {
+        Difficulty::Easy => 10,
+        Difficulty::Normal => 20,
+        Difficulty::Hard => 50,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ShapeDimensions {
+    Square {
+        side: u32,
+    },
+    Rectangle {
+        length: u32,
+        width: u32,
+    },
+    Triangle {
+        side_a: u32,
+        side_b: u32,
+        side_c: u32,
+    },
+    RegularPolygon {
+        corners: u32,
+        side: u32,
+    },
+    // A width × height rectangle with a cut_width × cut_height corner cut away
+    // at the top right
+    LShape {
+        width: u32,
+        height: u32,
+        cut_width: u32,
+        cut_height: u32,
+        inner_sides_shown: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerimeterQuestion {
+    shape_type: ShapeType,
+    dimensions: ShapeDimensions,
+    unit: String,
+    answer: Answer,
+}
+
+// The Swedish name of a regular polygon with this many corners
+fn polygon_name(corners: u32) -> String {
+    let count = match corners {
+        5 => "fem",
+        6 => "sex",
+        7 => "sju",
+        8 => "åtta",
+        9 => "nio",
+        10 => "tio",
+        _ => return format!("{}-hörning", corners),
+    };
+    format!("{}hörning", count)
+}
+
+impl PerimeterQuestion {
+    pub fn new_square(side: u32, unit: &str) -> Self {
+        Self {
+            shape_type: ShapeType::Square,
+            dimensions: ShapeDimensions::Square { side },
+            unit: unit.to_string(),
+            answer: Answer::Integer((4 * side).into()),
+        }
+    }
+
+    pub fn new_rectangle(length: u32, width: u32, unit: &str) -> Self {
+        Self {
+            shape_type: ShapeType::Rectangle,
+            dimensions: ShapeDimensions::Rectangle { length, width },
+            unit: unit.to_string(),
+            answer: Answer::Integer((2 * (length + width)).into()),
+        }
+    }
+
+    // The sides have to make a real triangle, so each is shorter than the other two together
+    pub fn new_triangle(side_a: u32, side_b: u32, side_c: u32, unit: &str) -> Self {
+        Self {
+            shape_type: ShapeType::Triangle,
+            dimensions: ShapeDimensions::Triangle {
+                side_a,
+                side_b,
+                side_c,
+            },
+            unit: unit.to_string(),
+            answer: Answer::Integer((side_a + side_b + side_c).into()),
+        }
+    }
+
+    pub fn new_regular_polygon(corners: u32, side: u32, unit: &str) -> Self {
+        Self {
+            shape_type: ShapeType::RegularPolygon,
+            dimensions: ShapeDimensions::RegularPolygon { corners, side },
+            unit: unit.to_string(),
+            answer: Answer::Integer((corners * side).into()),
+        }
+    }
+
+    pub fn new_l_shape(
+        width: u32,
+        height: u32,
+        cut_width: u32,
+        cut_height: u32,
+        inner_sides_shown: bool,
+        unit: &str,
+    ) -> Self {
+        // Moving the inner corner around doesn't change the perimeter, it is
+        // always that of the surrounding rectangle
+        Self {
+            shape_type: ShapeType::LShape,
+            dimensions: ShapeDimensions::LShape {
+                width,
+                height,
+                cut_width,
+                cut_height,
+                inner_sides_shown,
+            },
+            unit: unit.to_string(),
+            answer: Answer::Integer((2 * (width + height)).into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max_side = max_side(difficulty);
+        let units = ["cm", "m"];
+        let unit = units[rng.random_range(0..units.len())];
+        let shapes = if difficulty == Difficulty::Easy { 3 } else { 5 };
+
+        match rng.random_range(0..shapes) {
+            0 => Self::new_square(rng.random_range(1..max_side), unit),
+            1 => {
+                // Not too thin, so the labels fit next to the short sides
+                let length = rng.random_range(4..max_side);
+                let width = rng.random_range((length / 4).max(1)..length);
+                Self::new_rectangle(length, width, unit)
+            }
+            2 => {
+                let side_a = rng.random_range(3..max_side);
+                let side_b = rng.random_range(3..max_side);
+                let side_c = rng.random_range(side_a.abs_diff(side_b) + 1..side_a + side_b);
+                Self::new_triangle(side_a, side_b, side_c, unit)
+            }
+            3 => {
+                let max_corners = if difficulty == Difficulty::Hard {
+                    10
+                } else {
+                    8
+                };
+                let corners = rng.random_range(5..=max_corners);
+                Self::new_regular_polygon(corners, rng.random_range(2..max_side), unit)
+            }
+            _ => {
+                let width = rng.random_range(4..max_side);
+                let height = rng.random_range(4..max_side);
+                let cut_width = rng.random_range(1..width);
+                let cut_height = rng.random_range(1..height);
+                Self::new_l_shape(
+                    width,
+                    height,
+                    cut_width,
+                    cut_height,
+                    difficulty != Difficulty::Hard,
+                    unit,
+                )
+            }
+        }
+    }
+
+    fn shape_name(&self) -> String {
+        match &self.dimensions {
+            ShapeDimensions::RegularPolygon { corners, .. } => polygon_name(*corners),
+            _ => self.shape_type.to_string(),
+        }
+    }
+
+    // "the square", "the rectangle" and so on, as used in the prompt
+    fn definite_name(&self) -> String {
+        match self.shape_type {
+            ShapeType::Rectangle => "rektangeln".to_string(),
+            ShapeType::Triangle => "triangeln".to_string(),
+            ShapeType::LShape => "figuren".to_string(),
+            _ => format!("{}en", self.shape_name()),
+        }
+    }
+
+    fn label(&self, length: u32) -> Option<String> {
+        Some(format!("{} {}", length, self.unit))
+    }
+
+    // The corners and side labels in the question's own length unit
+    fn figure(&self) -> Figure {
+        match &self.dimensions {
+            ShapeDimensions::Square { side } => {
+                let s = *side as f64;
+                Figure {
+                    corners: vec![(0.0, 0.0), (s, 0.0), (s, s), (0.0, s)],
+                    // One side is enough, since all sides of a square are equal
+                    side_labels: vec![None, None, self.label(*side), None],
+                }
+            }
+            ShapeDimensions::Rectangle { length, width } => {
+                let (l, w) = (*length as f64, *width as f64);
+                Figure {
+                    corners: vec![(0.0, 0.0), (l, 0.0), (l, w), (0.0, w)],
+                    side_labels: vec![
+                        self.label(*length),
+                        self.label(*width),
+                        self.label(*length),
+                        self.label(*width),
+                    ],
+                }
+            }
+            ShapeDimensions::Triangle {
+                side_a,
+                side_b,
+                side_c,
+            } => {
+                // Side c lies along the bottom, and the top corner is placed so
+                // the other two sides get lengths a and b
+                let (a, b, c) = (*side_a as f64, *side_b as f64, *side_c as f64);
+                let x = (b * b + c * c - a * a) / (2.0 * c);
+                let y = (b * b - x * x).max(0.0).sqrt();
+                Figure {
+                    corners: vec![(0.0, y), (c, y), (x, 0.0)],
+                    side_labels: vec![
+                        self.label(*side_c),
+                        self.label(*side_a),
+                        self.label(*side_b),
+                    ],
+                }
+            }
+            ShapeDimensions::RegularPolygon { corners, side } => {
+                let count = *corners as usize;
+                let step = 2.0 * f64::consts::PI / count as f64;
+                let radius = *side as f64 / (2.0 * (step / 2.0).sin());
+                // Start at the bottom so the labelled side lies flat
+                let start = f64::consts::FRAC_PI_2 - step / 2.0;
+                let points = (0..count)
+                    .map(|i| {
+                        let angle = start + step * i as f64;
+                        (radius * angle.cos(), radius * angle.sin())
+                    })
+                    .collect();
+                let mut side_labels = vec![None; count];
+                side_labels[0] = self.label(*side);
+                Figure {
+                    corners: points,
+                    side_labels,
+                }
+            }
+            ShapeDimensions::LShape {
+                width,
+                height,
+                cut_width,
+                cut_height,
+                inner_sides_shown,
+            } => {
+                let (w, h) = (*width as f64, *height as f64);
+                let (cw, ch) = (*cut_width as f64, *cut_height as f64);
+                let inner = |length| {
+                    if *inner_sides_shown {
+                        self.label(length)
+                    } else {
+                        None
+                    }
+                };
+                Figure {
+                    corners: vec![
+                        (0.0, 0.0),
+                        (w - cw, 0.0),
+                        (w - cw, ch),
+                        (w, ch),
+                        (w, h),
+                        (0.0, h),
+                    ],
+                    side_labels: vec![
+                        self.label(width - cut_width),
+                        inner(*cut_height),
+                        inner(*cut_width),
+                        self.label(height - cut_height),
+                        self.label(*width),
+                        self.label(*height),
+                    ],
+                }
+            }
+        }
+    }
+}
+
+impl Question for PerimeterQuestion {
+    fn prompt(&self) -> String {
+        match &self.dimensions {
+            ShapeDimensions::RegularPolygon { .. } => format!(
+                "Alla sidor i {} är lika långa. Beräkna omkretsen.",
+                self.definite_name()
+            ),
+            _ => format!("Beräkna omkretsen av {}.", self.definite_name()),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let unit = &self.unit;
+        match &self.dimensions {
+            ShapeDimensions::Square { side } => vec![
+                "En kvadrat har fyra lika långa sidor.".to_string(),
+                format!("4 × {} = {} {}", side, self.answer, unit),
+            ],
+            ShapeDimensions::Rectangle { length, width } => vec![
+                "En rektangel har två långsidor och två kortsidor.".to_string(),
+                format!(
+                    "{} + {} + {} + {} = {} {}",
+                    length, width, length, width, self.answer, unit
+                ),
+            ],
+            ShapeDimensions::Triangle {
+                side_a,
+                side_b,
+                side_c,
+            } => vec![
+                "Lägg ihop triangelns tre sidor.".to_string(),
+                format!(
+                    "{} + {} + {} = {} {}",
+                    side_c, side_a, side_b, self.answer, unit
+                ),
+            ],
+            ShapeDimensions::RegularPolygon { corners, side } => vec![
+                format!("En {} har {} lika långa sidor.", self.shape_name(), corners),
+                format!("{} × {} = {} {}", corners, side, self.answer, unit),
+            ],
+            ShapeDimensions::LShape {
+                width,
+                height,
+                cut_width,
+                cut_height,
+                inner_sides_shown,
+            } => {
+                let mut steps = Vec::new();
+                if !inner_sides_shown {
+                    steps.push(format!(
+                        "De omärkta sidorna får du från de långa sidorna: {} − {} = {} och {} − {} = {}.",
+                        width,
+                        width - cut_width,
+                        cut_width,
+                        height,
+                        height - cut_height,
+                        cut_height
+                    ));
+                }
+                steps.push("Lägg ihop alla sex sidor.".to_string());
+                steps.push(format!(
+                    "{} + {} + {} + {} + {} + {} = {} {}",
+                    width - cut_width,
+                    cut_height,
+                    cut_width,
+                    height - cut_height,
+                    width,
+                    height,
+                    self.answer,
+                    unit
+                ));
+                steps
+            }
+        }
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Figure(self.figure())
+    }
+}
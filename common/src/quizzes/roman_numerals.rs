@@ -0,0 +1,383 @@
+use crate::{
+    answer::Answer,
+    quiz::{Choice, Difficulty, MultipleChoiceQuestionProvider, Question, QuestionView},
+};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+const SYMBOLS: [(u16, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+// The largest number with a standard Roman numeral, MMMCMXCIX
+pub const MAX_ROMAN: u16 = 3999;
+
+// Largest number asked about per difficulty
+fn max_number(difficulty: Difficulty) -> u16 {
+    match difficulty {
+        Difficulty::Easy => 20,
+        Difficulty::Normal => 100,
+        Difficulty::Hard => MAX_ROMAN,
+    }
+}
+
+// The symbols a number is written with, largest first, e.g. 47 is XL, V, I, I
+fn roman_parts(mut num: u16) -> Vec<(u16, &'static str)> {
+    let mut parts = Vec::new();
+    for &(value, symbol) in &SYMBOLS {
+        while num >= value {
+            parts.push((value, symbol));
+            num -= value;
+        }
+    }
+    parts
+}
+
+// Helper function to convert a number to a Roman numeral string.
+pub fn to_roman(num: u16) -> String {
+    roman_parts(num)
+        .into_iter()
+        .map(|(_, symbol)| symbol)
+        .collect()
+}
+
+// Reads a numeral in either case. Only the standard spelling is accepted, so
+// forms like IIII or VX that a greedy reading would make sense of give None.
+pub fn from_roman(numeral: &str) -> Option<u16> {
+    let numeral = numeral.trim().to_uppercase();
+    let mut rest = numeral.as_str();
+    let mut value: u32 = 0;
+    for &(symbol_value, symbol) in &SYMBOLS {
+        while let Some(after) = rest.strip_prefix(symbol) {
+            value += symbol_value as u32;
+            rest = after;
+        }
+    }
+    let value = u16::try_from(value).ok()?;
+    (rest.is_empty() && (1..=MAX_ROMAN).contains(&value) && to_roman(value) == numeral)
+        .then_some(value)
+}
+
+fn symbol_value(symbol: char) -> u16 {
+    SYMBOLS
+        .iter()
+        .find(|(_, s)| s.len() == 1 && s.starts_with(symbol))
+        .map_or(0, |(value, _)| *value)
+}
+
+// Values students get from typical misreadings of the numeral for `number`,
+// the most likely first
+fn misread_values(rng: &mut impl Rng, number: u16) -> Vec<u16> {
+    let numeral = to_roman(number);
+    let mut likely = Vec::new();
+    // Adding every symbol, e.g. IX read as 11
+    likely.push(numeral.chars().map(symbol_value).sum::<u16>());
+    // A subtracted symbol placed after the larger one, e.g. XI for IX
+    for (value, symbol) in roman_parts(number) {
+        if let [small, large] = symbol.chars().collect::<Vec<_>>()[..] {
+            likely.push(number - value + symbol_value(small) + symbol_value(large));
+        }
+    }
+    likely.shuffle(rng);
+    // One symbol too many or too few
+    let mut symbols: Vec<u16> = numeral.chars().map(symbol_value).collect();
+    symbols.dedup();
+    let mut off_by_one: Vec<u16> = symbols
+        .iter()
+        .flat_map(|value| [number + value, number.saturating_sub(*value)])
+        .collect();
+    off_by_one.shuffle(rng);
+
+    let mut values: Vec<u16> = Vec::new();
+    for value in likely.into_iter().chain(off_by_one) {
+        if value != number && (1..=MAX_ROMAN).contains(&value) && !values.contains(&value) {
+            values.push(value);
+        }
+    }
+    values
+}
+
+// The numeral parts per decimal place, e.g. 1994 is M, CM, XC and IV
+fn place_parts(number: u16) -> Vec<(u16, String)> {
+    [1000, 100, 10, 1]
+        .iter()
+        .map(|place| number / place % 10 * place)
+        .filter(|part| *part > 0)
+        .map(|part| (part, to_roman(part)))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Task {
+    // Pick the value of the numeral among four numbers
+    Read {
+        choices: Vec<u16>,
+    },
+    // Type the numeral for the number
+    Write,
+    // Pick the numeral, among four, that isn't written correctly. The number is
+    // what the wrong one was meant to be.
+    FindInvalid {
+        choices: Vec<String>,
+        invalid: usize,
+        rule: &'static str,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RomanNumeralsQuestion {
+    number: u16,
+    task: Task,
+}
+
+impl RomanNumeralsQuestion {
+    // Reading numerals, and on Normal and Hard also spotting wrongly written ones
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        if difficulty != Difficulty::Easy && rng.random_bool(0.3) {
+            Self::random_invalid(rng, difficulty)
+        } else {
+            Self::random_reading(rng, difficulty)
+        }
+    }
+
+    pub fn random_writing(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        Self {
+            number: rng.random_range(1..=max_number(difficulty)),
+            task: Task::Write,
+        }
+    }
+
+    fn random_reading(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let number = rng.random_range(1..=max_number(difficulty));
+        let mut choices = vec![number];
+        choices.extend(misread_values(rng, number).into_iter().take(3));
+        // Small numbers may not have three misreadings
+        while choices.len() < 4 {
+            let other = rng.random_range(1..=max_number(difficulty).max(10));
+            if !choices.contains(&other) {
+                choices.push(other);
+            }
+        }
+        choices.shuffle(rng);
+        Self {
+            number,
+            task: Task::Read { choices },
+        }
+    }
+
+    fn random_invalid(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max = max_number(difficulty);
+        // The places small enough to leave room for a digit, e.g. only ones up to 20
+        let places: Vec<u16> = [1, 10, 100]
+            .into_iter()
+            .filter(|place| place * 10 <= max)
+            .collect();
+        loop {
+            let place = places[rng.random_range(0..places.len())];
+            let (one, five, ten) = match place {
+                1 => ("I", "V", "X"),
+                10 => ("X", "L", "C"),
+                _ => ("C", "D", "M"),
+            };
+            // A digit written the wrong way, and the rule it breaks
+            let (digit, spelling, rule) = match rng.random_range(0..5) {
+                0 => (
+                    4,
+                    one.repeat(4),
+                    "Samma symbol skrivs högst tre gånger i rad.",
+                ),
+                1 => (
+                    9,
+                    format!("{}{}", five, one.repeat(4)),
+                    "Samma symbol skrivs högst tre gånger i rad.",
+                ),
+                2 => (
+                    8,
+                    format!("{}{}{}", one, one, ten),
+                    "Bara en mindre symbol får stå före en större.",
+                ),
+                3 => (
+                    5,
+                    format!("{}{}", five, ten),
+                    "V, L och D dras aldrig bort från en större symbol.",
+                ),
+                _ => (10, five.repeat(2), "V, L och D skrivs aldrig två gånger."),
+            };
+            let unit = place * 10;
+            let higher = unit * rng.random_range(0..=max / unit);
+            let lower = rng.random_range(0..place);
+            let number = higher + digit * place + lower;
+            let wrong = format!("{}{}{}", to_roman(higher), spelling, to_roman(lower));
+            if number > max || from_roman(&wrong).is_some() {
+                continue;
+            }
+
+            let mut choices = vec![wrong.clone()];
+            while choices.len() < 4 {
+                let other = to_roman(rng.random_range(1..=max));
+                if !choices.contains(&other) && other != to_roman(number) {
+                    choices.push(other);
+                }
+            }
+            choices.shuffle(rng);
+            let invalid = choices
+                .iter()
+                .position(|choice| *choice == wrong)
+                .unwrap_or_default();
+            return Self {
+                number,
+                task: Task::FindInvalid {
+                    choices,
+                    invalid,
+                    rule,
+                },
+            };
+        }
+    }
+}
+
+impl Question for RomanNumeralsQuestion {
+    fn prompt(&self) -> String {
+        match &self.task {
+            Task::Read { .. } => {
+                format!("Vad blir den romerska siffran {}?", to_roman(self.number))
+            }
+            Task::Write => format!("Skriv {} med romerska siffror.", self.number),
+            Task::FindInvalid { .. } => "Vilken romersk siffra är felskriven?".to_string(),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        match &self.task {
+            Task::Read { choices } => Answer::Choice {
+                index: choices
+                    .iter()
+                    .position(|&choice| choice == self.number)
+                    .unwrap_or_default(),
+                label: self.number.to_string(),
+            },
+            Task::Write => Answer::RomanNumeral(self.number),
+            Task::FindInvalid {
+                choices, invalid, ..
+            } => Answer::Choice {
+                index: *invalid,
+                label: choices[*invalid].clone(),
+            },
+        }
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let numeral = to_roman(self.number);
+        match &self.task {
+            Task::Read { .. } => {
+                let parts = roman_parts(self.number);
+                let symbols: Vec<String> = parts
+                    .iter()
+                    .map(|(value, symbol)| format!("{} = {}", symbol, value))
+                    .collect();
+                let values: Vec<String> =
+                    parts.iter().map(|(value, _)| value.to_string()).collect();
+                let mut steps = vec![format!(
+                    "Läs {} från vänster: {}",
+                    numeral,
+                    symbols.join(", ")
+                )];
+                if parts.iter().any(|(_, symbol)| symbol.len() == 2) {
+                    steps.push(
+                        "En mindre symbol före en större dras bort, till exempel IV = 5 - 1 = 4."
+                            .to_string(),
+                    );
+                }
+                steps.push(format!("{} = {}", values.join(" + "), self.number));
+                steps
+            }
+            Task::Write => {
+                let parts = place_parts(self.number);
+                let values: Vec<String> =
+                    parts.iter().map(|(value, _)| value.to_string()).collect();
+                let symbols: Vec<String> = parts
+                    .iter()
+                    .map(|(value, symbol)| format!("{} = {}", value, symbol))
+                    .collect();
+                // Numbers with a single digit above zero are written directly
+                if parts.len() == 1 {
+                    return vec![format!("{} = {}", self.number, numeral)];
+                }
+                vec![
+                    format!("Dela upp talet: {} = {}", self.number, values.join(" + ")),
+                    format!("Skriv varje del för sig: {}", symbols.join(", ")),
+                    format!("{} = {}", self.number, numeral),
+                ]
+            }
+            Task::FindInvalid {
+                choices,
+                invalid,
+                rule,
+            } => vec![
+                format!("{} är felskriven. {}", choices[*invalid], rule),
+                format!("{} skrivs {}.", self.number, numeral),
+            ],
+        }
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        match self.task {
+            Task::Write => QuestionView::Text,
+            _ => QuestionView::Choices(self.get_choices()),
+        }
+    }
+}
+
+impl MultipleChoiceQuestionProvider for RomanNumeralsQuestion {
+    fn get_choices(&self) -> Vec<Choice> {
+        let texts: Vec<String> = match &self.task {
+            Task::Read { choices } => choices.iter().map(|value| value.to_string()).collect(),
+            Task::FindInvalid { choices, .. } => choices.clone(),
+            Task::Write => Vec::new(),
+        };
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| Choice {
+                display_text: text,
+                value: index.to_string(), // The value to check against the answer
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_and_writes_numerals() {
+        assert_eq!(from_roman("XIV"), Some(14));
+        assert_eq!(from_roman("mcmxc"), Some(1990));
+        assert_eq!(to_roman(1990), "MCMXC");
+        for number in 1..=MAX_ROMAN {
+            assert_eq!(from_roman(&to_roman(number)), Some(number));
+        }
+    }
+
+    #[test]
+    fn rejects_non_canonical_numerals() {
+        assert_eq!(from_roman("IIII"), None);
+        assert_eq!(from_roman("VX"), None);
+        assert_eq!(from_roman("IC"), None);
+        assert_eq!(from_roman("XXXX"), None);
+        assert_eq!(from_roman(""), None);
+    }
+}
@@ -0,0 +1,64 @@
+use rand::Rng;
+
+use crate::{
+    answer::Answer,
+    quiz::{Difficulty, Question},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundingQuestion {
+    number: f32,
+    answer: Answer,
+}
+
+impl RoundingQuestion {
+    pub fn new(number: f32) -> Self {
+        Self {
+            number,
+            answer: Answer::Integer(number.round() as i64),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        // Easy numbers are positive, Hard ones go up to a thousand
+        let range = match difficulty {
+            Difficulty::Easy => 0.0..20.0,
+            Difficulty::Normal => -20.0..20.0,
+            Difficulty::Hard => -1000.0..1000.0,
+        };
+        let number: f32 = rng.random_range(range);
+        Self::new(number)
+    }
+}
+
+impl Question for RoundingQuestion {
+    fn prompt(&self) -> String {
+        format!("Avrunda till närmaste heltal: {}", self.number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let first_decimal = (self.number.abs() * 10.0).floor() as i64 % 10;
+        let rule = if first_decimal >= 5 {
+            format!(
+                "Eftersom {} är 5 eller mer avrundas talet bort från noll, till {}.",
+                first_decimal, self.answer
+            )
+        } else {
+            format!(
+                "Eftersom {} är mindre än 5 behålls heltalsdelen: {}",
+                first_decimal, self.answer
+            )
+        };
+        vec![
+            format!(
+                "Titta på första decimalen i {}: den är {}.",
+                self.number, first_decimal
+            ),
+            rule,
+        ]
+    }
+}
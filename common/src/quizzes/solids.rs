@@ -0,0 +1,450 @@
+use core::f64;
+use std::fmt;
+
+use rand::Rng;
+
+use crate::{
+    answer::{Answer, Tolerance},
+    number::format_number,
+    quiz::{Difficulty, Question, QuestionView},
+};
+
+// Longest edge, radius or height of the solids per difficulty
+fn max_length(difficulty: Difficulty) -> u32 {
+    match difficulty {
+        Difficulty::Easy => 10,
+        Difficulty::Normal => 15,
+        Difficulty::Hard => 30,
+    }
+}
+
+// Students may use π ≈ 3,14, which is well within one percent
+const PI_TOLERANCE: Tolerance = Tolerance::RelativeError(0.01);
+
+// Right triangles with whole sides, used where a slant height is needed so that
+// cones and pyramids get whole lengths in the drawing
+const PYTHAGOREAN_TRIPLES: [(u32, u32, u32); 6] = [
+    (3, 4, 5),
+    (4, 3, 5),
+    (6, 8, 10),
+    (8, 6, 10),
+    (5, 12, 13),
+    (12, 5, 13),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Solid {
+    Cube {
+        side: u32,
+    },
+    Cuboid {
+        length: u32,
+        width: u32,
+        height: u32,
+    },
+    Cylinder {
+        radius: u32,
+        height: u32,
+    },
+    // The slant is the length from the apex down to the edge of the base
+    Cone {
+        radius: u32,
+        height: u32,
+        slant: u32,
+    },
+    // A pyramid on a square base. The slant is the height of each side triangle.
+    Pyramid {
+        side: u32,
+        height: u32,
+        slant: u32,
+    },
+    Sphere {
+        radius: u32,
+    },
+}
+
+impl Solid {
+    fn uses_pi(&self) -> bool {
+        matches!(
+            self,
+            Solid::Cylinder { .. } | Solid::Cone { .. } | Solid::Sphere { .. }
+        )
+    }
+
+    fn volume(&self) -> f64 {
+        let pi = f64::consts::PI;
+        match *self {
+            Solid::Cube { side } => (side * side * side) as f64,
+            Solid::Cuboid {
+                length,
+                width,
+                height,
+            } => (length * width * height) as f64,
+            Solid::Cylinder { radius, height } => pi * (radius * radius * height) as f64,
+            Solid::Cone { radius, height, .. } => pi * (radius * radius * height) as f64 / 3.0,
+            Solid::Pyramid { side, height, .. } => (side * side * height) as f64 / 3.0,
+            Solid::Sphere { radius } => 4.0 * pi * (radius * radius * radius) as f64 / 3.0,
+        }
+    }
+
+    fn surface_area(&self) -> f64 {
+        let pi = f64::consts::PI;
+        match *self {
+            Solid::Cube { side } => (6 * side * side) as f64,
+            Solid::Cuboid {
+                length,
+                width,
+                height,
+            } => (2 * (length * width + length * height + width * height)) as f64,
+            Solid::Cylinder { radius, height } => {
+                2.0 * pi * (radius * radius) as f64 + 2.0 * pi * (radius * height) as f64
+            }
+            Solid::Cone { radius, slant, .. } => {
+                pi * (radius * radius) as f64 + pi * (radius * slant) as f64
+            }
+            Solid::Pyramid { side, slant, .. } => (side * side + 2 * side * slant) as f64,
+            Solid::Sphere { radius } => 4.0 * pi * (radius * radius) as f64,
+        }
+    }
+}
+
+impl fmt::Display for Solid {
+    // The definite form, as in "volymen av kuben"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Solid::Cube { .. } => write!(f, "kuben"),
+            Solid::Cuboid { .. } => write!(f, "rätblocket"),
+            Solid::Cylinder { .. } => write!(f, "cylindern"),
+            Solid::Cone { .. } => write!(f, "konen"),
+            Solid::Pyramid { .. } => write!(f, "pyramiden"),
+            Solid::Sphere { .. } => write!(f, "klotet"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LengthUnit {
+    Centimetre,
+    Decimetre,
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthUnit::Centimetre => write!(f, "cm"),
+            LengthUnit::Decimetre => write!(f, "dm"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VolumeUnit {
+    CubicCentimetre,
+    CubicDecimetre,
+    Litre,
+}
+
+impl VolumeUnit {
+    // The unit of a volume computed from lengths in `length_unit`
+    fn cubic(length_unit: LengthUnit) -> Self {
+        match length_unit {
+            LengthUnit::Centimetre => VolumeUnit::CubicCentimetre,
+            LengthUnit::Decimetre => VolumeUnit::CubicDecimetre,
+        }
+    }
+
+    // Converts a volume in cubic `length_unit` to this unit. 1 dm³ is 1 liter
+    // and 1000 cm³. Dividing rather than multiplying by 0.001 keeps values like
+    // 0,819 free of floating point noise.
+    fn convert(&self, volume: f64, length_unit: LengthUnit) -> f64 {
+        match (length_unit, self) {
+            (LengthUnit::Centimetre, VolumeUnit::CubicCentimetre)
+            | (LengthUnit::Decimetre, VolumeUnit::CubicDecimetre | VolumeUnit::Litre) => volume,
+            (LengthUnit::Centimetre, VolumeUnit::CubicDecimetre | VolumeUnit::Litre) => {
+                volume / 1000.0
+            }
+            (LengthUnit::Decimetre, VolumeUnit::CubicCentimetre) => volume * 1000.0,
+        }
+    }
+}
+
+impl fmt::Display for VolumeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VolumeUnit::CubicCentimetre => write!(f, "cm³"),
+            VolumeUnit::CubicDecimetre => write!(f, "dm³"),
+            VolumeUnit::Litre => write!(f, "liter"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Measure {
+    Volume(VolumeUnit),
+    // Always in the square of the length unit
+    SurfaceArea,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolidQuestion {
+    solid: Solid,
+    length_unit: LengthUnit,
+    measure: Measure,
+    answer: Answer,
+}
+
+impl SolidQuestion {
+    pub fn new(solid: Solid, length_unit: LengthUnit, measure: Measure) -> Self {
+        let value = match measure {
+            Measure::Volume(unit) => unit.convert(solid.volume(), length_unit),
+            Measure::SurfaceArea => solid.surface_area(),
+        };
+        let tolerance = if solid.uses_pi() {
+            PI_TOLERANCE
+        } else {
+            Tolerance::Exact
+        };
+        Self {
+            solid,
+            length_unit,
+            measure,
+            answer: Answer::decimal(value, tolerance),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let max = max_length(difficulty);
+        // Easy only asks for volumes of the simplest solids
+        let solids = if difficulty == Difficulty::Easy { 3 } else { 6 };
+        let solid = match rng.random_range(0..solids) {
+            0 => Solid::Cube {
+                side: rng.random_range(1..=max),
+            },
+            1 => Solid::Cuboid {
+                length: rng.random_range(2..=max),
+                width: rng.random_range(1..=max),
+                height: rng.random_range(1..=max),
+            },
+            2 => Solid::Cylinder {
+                radius: rng.random_range(1..=max / 2),
+                height: rng.random_range(1..=max),
+            },
+            3 => {
+                let (radius, height, slant) = Self::random_triple(rng, difficulty);
+                Solid::Cone {
+                    radius,
+                    height,
+                    slant,
+                }
+            }
+            4 => {
+                // Half the side and the height make a right triangle with the
+                // slant. One leg of such a triangle is always divisible by 3, so
+                // the volume 4 × half² × height / 3 is a whole number.
+                let (half_side, height, slant) = Self::random_triple(rng, difficulty);
+                Solid::Pyramid {
+                    side: 2 * half_side,
+                    height,
+                    slant,
+                }
+            }
+            _ => Solid::Sphere {
+                radius: rng.random_range(1..=max / 2),
+            },
+        };
+
+        let length_unit = if rng.random_bool(0.5) {
+            LengthUnit::Centimetre
+        } else {
+            LengthUnit::Decimetre
+        };
+        let same_unit = VolumeUnit::cubic(length_unit);
+        // Normal also asks for liters when that is the same as dm³, Hard for any unit
+        let volume_unit = match difficulty {
+            Difficulty::Easy => same_unit,
+            Difficulty::Normal if length_unit == LengthUnit::Decimetre && rng.random_bool(0.5) => {
+                VolumeUnit::Litre
+            }
+            Difficulty::Normal => same_unit,
+            Difficulty::Hard => [
+                VolumeUnit::CubicCentimetre,
+                VolumeUnit::CubicDecimetre,
+                VolumeUnit::Litre,
+            ][rng.random_range(0..3)],
+        };
+        let measure = if difficulty != Difficulty::Easy && rng.random_bool(0.4) {
+            Measure::SurfaceArea
+        } else {
+            Measure::Volume(volume_unit)
+        };
+
+        Self::new(solid, length_unit, measure)
+    }
+
+    // A scaled Pythagorean triple, on Hard up to twice as large
+    fn random_triple(rng: &mut impl Rng, difficulty: Difficulty) -> (u32, u32, u32) {
+        let (a, b, c) = PYTHAGOREAN_TRIPLES[rng.random_range(0..PYTHAGOREAN_TRIPLES.len())];
+        let scale = if difficulty == Difficulty::Hard {
+            rng.random_range(1..=2)
+        } else {
+            1
+        };
+        (a * scale, b * scale, c * scale)
+    }
+
+    // Getters for the drawing of the solid
+    pub fn solid(&self) -> &Solid {
+        &self.solid
+    }
+
+    // The drawing shows the slant heights only when they are needed
+    pub fn asks_for_surface_area(&self) -> bool {
+        self.measure == Measure::SurfaceArea
+    }
+
+    // A length as it is written in the drawing, e.g. "5 cm"
+    pub fn length_label(&self, length: u32) -> String {
+        format!("{} {}", length, self.length_unit)
+    }
+
+    fn answer_unit(&self) -> String {
+        match self.measure {
+            Measure::Volume(unit) => unit.to_string(),
+            Measure::SurfaceArea => format!("{}²", self.length_unit),
+        }
+    }
+
+    // The formula in words, and the same with the lengths filled in
+    fn formula(&self) -> (String, String) {
+        let volume = matches!(self.measure, Measure::Volume(_));
+        match (&self.solid, volume) {
+            (Solid::Cube { side }, true) => (
+                "Volymen av en kub är sidan³.".to_string(),
+                format!("{}³", side),
+            ),
+            (Solid::Cube { side }, false) => (
+                "En kub har sex kvadratiska sidor.".to_string(),
+                format!("6 × {}²", side),
+            ),
+            (
+                Solid::Cuboid {
+                    length,
+                    width,
+                    height,
+                },
+                true,
+            ) => (
+                "Volymen av ett rätblock är längden × bredden × höjden.".to_string(),
+                format!("{} × {} × {}", length, width, height),
+            ),
+            (
+                Solid::Cuboid {
+                    length,
+                    width,
+                    height,
+                },
+                false,
+            ) => (
+                "Ett rätblock har tre par lika stora rektanglar som sidor.".to_string(),
+                format!(
+                    "2 × ({} × {} + {} × {} + {} × {})",
+                    length, width, length, height, width, height
+                ),
+            ),
+            (Solid::Cylinder { radius, height }, true) => (
+                "Volymen av en cylinder är π × r² × h.".to_string(),
+                format!("π × {}² × {}", radius, height),
+            ),
+            (Solid::Cylinder { radius, height }, false) => (
+                "En cylinder har två cirklar som botten och lock, och en mantelyta som är 2 × π × r × h."
+                    .to_string(),
+                format!("2 × π × {}² + 2 × π × {} × {}", radius, radius, height),
+            ),
+            (Solid::Cone { radius, height, .. }, true) => (
+                "Volymen av en kon är π × r² × h / 3.".to_string(),
+                format!("π × {}² × {} / 3", radius, height),
+            ),
+            (Solid::Cone { radius, slant, .. }, false) => (
+                "En kon har en cirkel som botten, och en mantelyta som är π × r × sidan.".to_string(),
+                format!("π × {}² + π × {} × {}", radius, radius, slant),
+            ),
+            (Solid::Pyramid { side, height, .. }, true) => (
+                "Volymen av en pyramid är basytan × höjden / 3.".to_string(),
+                format!("{}² × {} / 3", side, height),
+            ),
+            (Solid::Pyramid { side, slant, .. }, false) => (
+                "En pyramid har en kvadrat som botten och fyra trianglar som sidor. Trianglarnas höjd är sidohöjden."
+                    .to_string(),
+                format!("{}² + 4 × {} × {} / 2", side, side, slant),
+            ),
+            (Solid::Sphere { radius }, true) => (
+                "Volymen av ett klot är 4 × π × r³ / 3.".to_string(),
+                format!("4 × π × {}³ / 3", radius),
+            ),
+            (Solid::Sphere { radius }, false) => (
+                "Arean av ett klot är 4 × π × r².".to_string(),
+                format!("4 × π × {}²", radius),
+            ),
+        }
+    }
+}
+
+impl Question for SolidQuestion {
+    fn prompt(&self) -> String {
+        match self.measure {
+            Measure::Volume(unit) => format!("Beräkna volymen av {} i {}.", self.solid, unit),
+            Measure::SurfaceArea => format!(
+                "Beräkna begränsningsarean av {} i {}.",
+                self.solid,
+                self.answer_unit()
+            ),
+        }
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        let (rule, calculation) = self.formula();
+        let mut steps = vec![rule];
+        match self.measure {
+            Measure::Volume(unit) if unit != VolumeUnit::cubic(self.length_unit) => {
+                let volume = self.solid.volume();
+                steps.push(format!(
+                    "{} = {} {}³",
+                    calculation,
+                    format_number(volume),
+                    self.length_unit
+                ));
+                steps.push(format!(
+                    "1 dm³ = 1 liter = 1000 cm³, så {} {}³ = {} {}",
+                    format_number(volume),
+                    self.length_unit,
+                    self.answer,
+                    unit
+                ));
+            }
+            Measure::Volume(unit) => {
+                steps.push(format!(
+                    "{} {}",
+                    self.answer.equation(&calculation, self.solid.volume()),
+                    unit
+                ));
+            }
+            Measure::SurfaceArea => {
+                steps.push(format!(
+                    "{} {}",
+                    self.answer
+                        .equation(&calculation, self.solid.surface_area()),
+                    self.answer_unit()
+                ));
+            }
+        }
+        steps
+    }
+
+    fn view(&self) -> QuestionView<'_> {
+        QuestionView::Solid(self)
+    }
+}
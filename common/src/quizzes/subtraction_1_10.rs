@@ -0,0 +1,67 @@
+use crate::{answer::Answer, quiz::*};
+use rand::Rng;
+
+// Range of the first number per difficulty
+fn range(difficulty: Difficulty) -> (i32, i32) {
+    match difficulty {
+        Difficulty::Easy => (1, 5),
+        Difficulty::Normal => (1, 10),
+        Difficulty::Hard => (5, 10),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtractionQuestion1_10 {
+    first_number: i32,
+    second_number: i32,
+    answer: Answer,
+}
+
+impl SubtractionQuestion1_10 {
+    pub fn new(first: i32, second: i32) -> Self {
+        let result = first - second;
+
+        Self {
+            first_number: first,
+            second_number: second,
+            answer: Answer::Integer(result.into()),
+        }
+    }
+
+    pub fn random(rng: &mut impl Rng, difficulty: Difficulty) -> Self {
+        let range = range(difficulty);
+        let left = rng.random_range(range.0..=range.1);
+        Self::new(left, rng.random_range(range.0..=left))
+    }
+}
+
+impl Question for SubtractionQuestion1_10 {
+    fn prompt(&self) -> String {
+        format!("Beräkna {} - {}?", self.first_number, self.second_number)
+    }
+
+    fn answer(&self) -> Answer {
+        self.answer.clone()
+    }
+
+    fn explanation(&self) -> Vec<String> {
+        // Count back from the first number
+        let difference = self.first_number - self.second_number;
+        let counted: Vec<String> = (difference..self.first_number)
+            .rev()
+            .map(|number| number.to_string())
+            .collect();
+        vec![
+            format!(
+                "Börja på {} och räkna {} steg nedåt: {}",
+                self.first_number,
+                self.second_number,
+                counted.join(", ")
+            ),
+            format!(
+                "{} - {} = {}",
+                self.first_number, self.second_number, self.answer
+            ),
+        ]
+    }
+}
@@ -1,4 +1,8 @@
-use common::{LeaderboardEntry, SubmitScoreRequest, TopUserSchoolEntry, config::API_BASE_URL};
+use common::{
+    config::API_BASE_URL, custom_quiz::CustomQuiz, quiz::Difficulty, CreateCustomQuizRequest,
+    FinishSessionRequest, FinishSessionResponse, LeaderboardEntry, School, SchoolAccessRequest,
+    StartSessionRequest, StartSessionResponse, SubmitScoreRequest, TopUserSchoolEntry,
+};
 use gloo_net::http::Request;
 use serde::de::DeserializeOwned;
 use thiserror::Error;
@@ -70,6 +74,7 @@ async fn handle_submit_response(response: gloo_net::http::Response) -> Result<()
 // Ensure this is the version you have:
 pub async fn fetch_leaderboard(
     course: &str,
+    difficulty: Difficulty,
     school: &str,
     school_id: &Uuid,
 ) -> Result<Vec<LeaderboardEntry>, ApiError> {
@@ -81,14 +86,21 @@ pub async fn fetch_leaderboard(
     let url = if API_BASE_URL.is_empty() {
         // In production, use relative URLs that work regardless of domain
         format!(
-            "/api/leaderboard?course={}&school={}&school_id={}",
-            encoded_course, encoded_school, school_id_str
+            "/api/leaderboard?course={}&difficulty={}&school={}&school_id={}",
+            encoded_course,
+            difficulty.id(),
+            encoded_school,
+            school_id_str
         )
     } else {
         // In development, use the full URL with localhost
         format!(
-            "{}/api/leaderboard?course={}&school={}&school_id={}",
-            API_BASE_URL, encoded_course, encoded_school, school_id_str
+            "{}/api/leaderboard?course={}&difficulty={}&school={}&school_id={}",
+            API_BASE_URL,
+            encoded_course,
+            difficulty.id(),
+            encoded_school,
+            school_id_str
         )
     };
     // Optional: Keep this log for debugging
@@ -142,3 +154,83 @@ pub async fn fetch_top_users_by_school(
     let response = Request::get(&url).send().await?;
     handle_response(response).await
 }
+
+pub async fn redeem_access_code(access_code: &str) -> Result<School, ApiError> {
+    let url = if API_BASE_URL.is_empty() {
+        "/api/school/access".to_string()
+    } else {
+        format!("{}/api/school/access", API_BASE_URL)
+    };
+
+    let req = SchoolAccessRequest {
+        access_code: access_code.trim().to_string(),
+    };
+
+    let response = Request::post(&url)
+        .json(&req)
+        .map_err(|e| ApiError::Build(format!("Failed to serialize request: {}", e)))?
+        .send()
+        .await?;
+
+    handle_response(response).await
+}
+
+pub async fn start_session(req: &StartSessionRequest) -> Result<StartSessionResponse, ApiError> {
+    let url = if API_BASE_URL.is_empty() {
+        "/api/session/start".to_string()
+    } else {
+        format!("{}/api/session/start", API_BASE_URL)
+    };
+
+    let response = Request::post(&url)
+        .json(req)
+        .map_err(|e| ApiError::Build(format!("Failed to serialize request: {}", e)))?
+        .send()
+        .await?;
+
+    handle_response(response).await
+}
+
+pub async fn finish_session(req: &FinishSessionRequest) -> Result<FinishSessionResponse, ApiError> {
+    let url = if API_BASE_URL.is_empty() {
+        "/api/session/finish".to_string()
+    } else {
+        format!("{}/api/session/finish", API_BASE_URL)
+    };
+
+    let response = Request::post(&url)
+        .json(req)
+        .map_err(|e| ApiError::Build(format!("Failed to serialize request: {}", e)))?
+        .send()
+        .await?;
+
+    handle_response(response).await
+}
+
+pub async fn fetch_custom_quiz(code: &str) -> Result<CustomQuiz, ApiError> {
+    let encoded_code = encode_uri_component(code.trim());
+    let url = if API_BASE_URL.is_empty() {
+        format!("/api/custom_quiz/{}", encoded_code)
+    } else {
+        format!("{}/api/custom_quiz/{}", API_BASE_URL, encoded_code)
+    };
+
+    let response = Request::get(&url).send().await?;
+    handle_response(response).await
+}
+
+pub async fn create_custom_quiz(req: &CreateCustomQuizRequest) -> Result<CustomQuiz, ApiError> {
+    let url = if API_BASE_URL.is_empty() {
+        "/api/custom_quiz".to_string()
+    } else {
+        format!("{}/api/custom_quiz", API_BASE_URL)
+    };
+
+    let response = Request::post(&url)
+        .json(req)
+        .map_err(|e| ApiError::Build(format!("Failed to serialize request: {}", e)))?
+        .send()
+        .await?;
+
+    handle_response(response).await
+}
@@ -1,4 +1,4 @@
-use crate::quizzes::ClockReadingQuestion; // Assuming this path is correct
+use common::quizzes::ClockReadingQuestion;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -53,83 +53,7 @@ pub fn analog_clock(props: &AnalogClockProps) -> Html {
                 class="analog-clock-svg"
                 style={svg_style}
             >
-                // Clock face
-                <circle
-                    class="clock-face"
-                    cx={center.to_string()}
-                    cy={center.to_string()}
-                    r={radius.to_string()}
-                />
-
-                // Hour markers (thicker)
-                {
-                    (0..12).map(|i| {
-                        let angle_rad = ((i as f64) * 30.0 - 90.0).to_radians();
-                        let marker_length = radius as f64 * 0.15;
-                        let outer_r = radius as f64;
-                        let inner_r = outer_r - marker_length;
-
-                        let x1 = center as f64 + inner_r * angle_rad.cos();
-                        let y1 = center as f64 + inner_r * angle_rad.sin();
-                        let x2 = center as f64 + outer_r * angle_rad.cos();
-                        let y2 = center as f64 + outer_r * angle_rad.sin();
-
-                        html! {
-                            <line
-                                class="hour-marker"
-                                x1={x1.to_string()} y1={y1.to_string()}
-                                x2={x2.to_string()} y2={y2.to_string()}
-                                key={format!("h-marker-{}",i)}
-                            />
-                        }
-                    }).collect::<Html>()
-                }
-
-                // Minute markers (thinner)
-                {
-                    (0..60).filter(|i| i % 5 != 0).map(|i| {
-                        let angle_rad = ((i as f64) * 6.0 - 90.0).to_radians();
-                        let marker_length = radius as f64 * 0.08;
-                        let outer_r = radius as f64;
-                        let inner_r = outer_r - marker_length;
-
-                        let x1 = center as f64 + inner_r * angle_rad.cos();
-                        let y1 = center as f64 + inner_r * angle_rad.sin();
-                        let x2 = center as f64 + outer_r * angle_rad.cos();
-                        let y2 = center as f64 + outer_r * angle_rad.sin();
-
-                        html! {
-                            <line
-                                class="minute-marker"
-                                x1={x1.to_string()} y1={y1.to_string()}
-                                x2={x2.to_string()} y2={y2.to_string()}
-                                key={format!("m-marker-{}",i)}
-                            />
-                        }
-                    }).collect::<Html>()
-                }
-
-                // Hour numbers
-                {
-                    (1..=12).map(|i| {
-                        let angle_rad = ((i as f64) * 30.0 - 90.0).to_radians();
-                        let text_r = radius as f64 * 0.75;
-
-                        let x = center as f64 + text_r * angle_rad.cos();
-                        let y = center as f64 + text_r * angle_rad.sin();
-
-                        html! {
-                            <text
-                                class="hour-number"
-                                x={x.to_string()}
-                                y={y.to_string()}
-                                key={format!("h-num-{}",i)}
-                            >
-                                {i.to_string()}
-                            </text>
-                        }
-                    }).collect::<Html>()
-                }
+                { clock_face(center as f64, radius as f64) }
 
                 // Hour hand
                 <line class="hour-hand"
@@ -158,3 +82,89 @@ pub fn analog_clock(props: &AnalogClockProps) -> Html {
         </div>
     }
 }
+
+// The dial without hands: the face, the markers and the hour numbers. Shared by
+// the clock that shows a time and the one the student sets.
+pub(crate) fn clock_face(center: f64, radius: f64) -> Html {
+    html! {
+        <>
+            // Clock face
+            <circle
+                class="clock-face"
+                cx={center.to_string()}
+                cy={center.to_string()}
+                r={radius.to_string()}
+            />
+
+            // Hour markers (thicker)
+            {
+                (0..12).map(|i| {
+                    let angle_rad = ((i as f64) * 30.0 - 90.0).to_radians();
+                    let marker_length = radius * 0.15;
+                    let outer_r = radius;
+                    let inner_r = outer_r - marker_length;
+
+                    let x1 = center + inner_r * angle_rad.cos();
+                    let y1 = center + inner_r * angle_rad.sin();
+                    let x2 = center + outer_r * angle_rad.cos();
+                    let y2 = center + outer_r * angle_rad.sin();
+
+                    html! {
+                        <line
+                            class="hour-marker"
+                            x1={x1.to_string()} y1={y1.to_string()}
+                            x2={x2.to_string()} y2={y2.to_string()}
+                            key={format!("h-marker-{}",i)}
+                        />
+                    }
+                }).collect::<Html>()
+            }
+
+            // Minute markers (thinner)
+            {
+                (0..60).filter(|i| i % 5 != 0).map(|i| {
+                    let angle_rad = ((i as f64) * 6.0 - 90.0).to_radians();
+                    let marker_length = radius * 0.08;
+                    let outer_r = radius;
+                    let inner_r = outer_r - marker_length;
+
+                    let x1 = center + inner_r * angle_rad.cos();
+                    let y1 = center + inner_r * angle_rad.sin();
+                    let x2 = center + outer_r * angle_rad.cos();
+                    let y2 = center + outer_r * angle_rad.sin();
+
+                    html! {
+                        <line
+                            class="minute-marker"
+                            x1={x1.to_string()} y1={y1.to_string()}
+                            x2={x2.to_string()} y2={y2.to_string()}
+                            key={format!("m-marker-{}",i)}
+                        />
+                    }
+                }).collect::<Html>()
+            }
+
+            // Hour numbers
+            {
+                (1..=12).map(|i| {
+                    let angle_rad = ((i as f64) * 30.0 - 90.0).to_radians();
+                    let text_r = radius * 0.75;
+
+                    let x = center + text_r * angle_rad.cos();
+                    let y = center + text_r * angle_rad.sin();
+
+                    html! {
+                        <text
+                            class="hour-number"
+                            x={x.to_string()}
+                            y={y.to_string()}
+                            key={format!("h-num-{}",i)}
+                        >
+                            {i.to_string()}
+                        </text>
+                    }
+                }).collect::<Html>()
+            }
+        </>
+    }
+}
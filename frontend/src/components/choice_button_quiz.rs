@@ -1,25 +1,20 @@
-use crate::quiz::MultipleChoiceQuestionProvider;
+use common::quiz::Choice;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
-pub struct ChoiceButtonQuizComponentProps<Q>
-where
-    Q: MultipleChoiceQuestionProvider + 'static,
-{
-    pub question: Q,
+pub struct ChoiceButtonQuizComponentProps {
+    pub prompt: String,
+    pub choices: Vec<Choice>,
     pub on_answer: Callback<String>,
 }
 
 #[function_component]
-pub fn ChoiceButtonQuizComponent<Q>(props: &ChoiceButtonQuizComponentProps<Q>) -> Html
-where
-    Q: MultipleChoiceQuestionProvider + 'static,
-{
-    let choices = props.question.get_choices();
+pub fn ChoiceButtonQuizComponent(props: &ChoiceButtonQuizComponentProps) -> Html {
+    let choices = props.choices.clone();
 
     html! {
         <div class="choice-button-quiz-container">
-            <h2>{ props.question.display() }</h2>
+            <h2>{ props.prompt.clone() }</h2>
 
             <div class="choice-options"> // Container for the buttons
                 {
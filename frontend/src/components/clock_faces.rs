@@ -0,0 +1,26 @@
+use super::AnalogClock;
+use common::quizzes::ClockReadingQuestion;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ClockFacesProps {
+    pub clocks: Vec<(&'static str, ClockReadingQuestion)>,
+    pub size: Option<u32>, // Optional size of each clock in pixels
+}
+
+// Clocks side by side, each with a caption such as "Avgång" below it
+#[function_component(ClockFaces)]
+pub fn clock_faces(props: &ClockFacesProps) -> Html {
+    html! {
+        <div class="clock-faces">
+            {
+                props.clocks.iter().map(|(caption, clock)| html! {
+                    <figure class="captioned-clock">
+                        <AnalogClock question={clock.clone()} size={props.size} />
+                        <figcaption>{ *caption }</figcaption>
+                    </figure>
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
@@ -0,0 +1,261 @@
+use super::clock_face;
+use web_sys::{Element, PointerEvent};
+use yew::prelude::*;
+
+// The minute hand snaps to five minutes, like the numbers on the dial
+const MINUTE_STEP: i32 = 5;
+
+// Minutes on the dial, from 12:00 round to 11:59
+const MINUTES_ON_DIAL: i32 = 12 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Hand {
+    Hour,
+    Minute,
+}
+
+// Degrees from 12 clockwise
+fn hand_angle(hand: Hand, time: i32) -> f64 {
+    match hand {
+        Hand::Hour => time as f64 * 0.5,
+        Hand::Minute => (time % 60) as f64 * 6.0,
+    }
+}
+
+// The time with one hand turned to point at `angle`. The hour hand snaps to the
+// hour nearest to where it is put, taking the minutes into account.
+fn turned_to(hand: Hand, time: i32, angle: f64) -> i32 {
+    let (hour, minute) = (time / 60, time % 60);
+    match hand {
+        Hand::Hour => {
+            let hour = ((angle - minute as f64 * 0.5) / 30.0).round() as i32;
+            hour.rem_euclid(12) * 60 + minute
+        }
+        Hand::Minute => {
+            let minute = (angle / 6.0 / MINUTE_STEP as f64).round() as i32 * MINUTE_STEP;
+            hour * 60 + minute.rem_euclid(60)
+        }
+    }
+}
+
+// The time one step forward or back. The hour hand steps an hour and the
+// minute hand five minutes, staying within the hour.
+fn stepped(hand: Hand, time: i32, forward: bool) -> i32 {
+    let direction = if forward { 1 } else { -1 };
+    match hand {
+        Hand::Hour => (time + direction * 60).rem_euclid(MINUTES_ON_DIAL),
+        Hand::Minute => {
+            let minute = (time % 60 + direction * MINUTE_STEP).rem_euclid(60);
+            time / 60 * 60 + minute
+        }
+    }
+}
+
+// Difference between two angles in degrees, at most 180
+fn angle_between(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ClockSetterProps {
+    pub prompt: String,
+    pub on_answer: Callback<String>,
+    pub size: Option<u32>, // Optional size in pixels
+}
+
+// A clock whose hands are dragged, or moved with the arrow keys when focused,
+// answered with the time they show as HH:MM in 24-hour format
+#[function_component(ClockSetter)]
+pub fn clock_setter(props: &ClockSetterProps) -> Html {
+    let size = props.size.unwrap_or(200);
+    let center = size as f64 / 2.0;
+    let radius = size as f64 * 0.9 / 2.0;
+    let hour_hand_length = radius * 0.55;
+    let minute_hand_length = radius * 0.75;
+
+    let svg_ref = use_node_ref();
+    // Minutes after 12 on the dial, and whether it is afternoon
+    let time = use_state(|| 0);
+    let is_afternoon = use_state(|| false);
+    let dragging = use_state(|| None::<Hand>);
+
+    // Start from 12 on the morning when the next question comes
+    {
+        let time = time.clone();
+        let is_afternoon = is_afternoon.clone();
+        use_effect_with(props.prompt.clone(), move |_| {
+            time.set(0);
+            is_afternoon.set(false);
+        });
+    }
+
+    // Where the pointer is, as degrees from 12 and distance from the center in
+    // the clock's own units
+    let pointer_position = {
+        let svg_ref = svg_ref.clone();
+        move |e: &PointerEvent| -> Option<(f64, f64)> {
+            let svg = svg_ref.cast::<Element>()?;
+            let rect = svg.get_bounding_client_rect();
+            if rect.width() == 0.0 {
+                return None;
+            }
+            let scale = size as f64 / rect.width();
+            let x = (e.client_x() as f64 - rect.left()) * scale - center;
+            let y = (e.client_y() as f64 - rect.top()) * scale - center;
+            Some((x.atan2(-y).to_degrees().rem_euclid(360.0), x.hypot(y)))
+        }
+    };
+
+    let on_pointer_down = {
+        let svg_ref = svg_ref.clone();
+        let pointer_position = pointer_position.clone();
+        let time = time.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |e: PointerEvent| {
+            let Some((angle, distance)) = pointer_position(&e) else {
+                return;
+            };
+            e.prevent_default();
+            // Grab the hand nearest to the pointer. When both are about as near,
+            // the short hand is grabbed close to the center.
+            let to_hour = angle_between(angle, hand_angle(Hand::Hour, *time));
+            let to_minute = angle_between(angle, hand_angle(Hand::Minute, *time));
+            let hand = if (to_hour - to_minute).abs() < 15.0 {
+                if distance < hour_hand_length {
+                    Hand::Hour
+                } else {
+                    Hand::Minute
+                }
+            } else if to_hour < to_minute {
+                Hand::Hour
+            } else {
+                Hand::Minute
+            };
+            if let Some(svg) = svg_ref.cast::<Element>() {
+                svg.set_pointer_capture(e.pointer_id()).unwrap_or_default();
+            }
+            time.set(turned_to(hand, *time, angle));
+            dragging.set(Some(hand));
+        })
+    };
+
+    let on_pointer_move = {
+        let time = time.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |e: PointerEvent| {
+            if let (Some(hand), Some((angle, _))) = (*dragging, pointer_position(&e)) {
+                time.set(turned_to(hand, *time, angle));
+            }
+        })
+    };
+
+    let on_pointer_up = {
+        let dragging = dragging.clone();
+        Callback::from(move |_: PointerEvent| dragging.set(None))
+    };
+
+    let on_key_down = |hand: Hand| {
+        let time = time.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let forward = match e.key().as_str() {
+                "ArrowUp" | "ArrowRight" => true,
+                "ArrowDown" | "ArrowLeft" => false,
+                _ => return,
+            };
+            e.prevent_default();
+            time.set(stepped(hand, *time, forward));
+        })
+    };
+
+    let on_period = |afternoon: bool| {
+        let is_afternoon = is_afternoon.clone();
+        Callback::from(move |_: MouseEvent| is_afternoon.set(afternoon))
+    };
+
+    let on_submit = {
+        let time = time.clone();
+        let is_afternoon = is_afternoon.clone();
+        let on_answer = props.on_answer.clone();
+        Callback::from(move |_: MouseEvent| {
+            let hour = *time / 60 + if *is_afternoon { 12 } else { 0 };
+            on_answer.emit(format!("{:02}:{:02}", hour, *time % 60));
+        })
+    };
+
+    let hand_html = |hand: Hand, length: f64| {
+        let angle_rad = (hand_angle(hand, *time) - 90.0).to_radians();
+        let (class, label, value_text) = match hand {
+            Hand::Hour => {
+                let hour = match *time / 60 {
+                    0 => 12,
+                    hour => hour,
+                };
+                ("hour-hand", "Timvisare", format!("{}", hour))
+            }
+            Hand::Minute => (
+                "minute-hand",
+                "Minutvisare",
+                format!("{} minuter", *time % 60),
+            ),
+        };
+        html! {
+            <line
+                class={classes!(class, (*dragging == Some(hand)).then_some("dragging"))}
+                x1={center.to_string()} y1={center.to_string()}
+                x2={(center + length * angle_rad.cos()).to_string()}
+                y2={(center + length * angle_rad.sin()).to_string()}
+                tabindex="0"
+                role="slider"
+                aria-label={label}
+                aria-valuetext={value_text}
+                onkeydown={on_key_down(hand)}
+            />
+        }
+    };
+
+    let period_button = |afternoon: bool, text: &'static str| {
+        let period = if afternoon { "afternoon" } else { "morning" };
+        let is_picked = *is_afternoon == afternoon;
+        html! {
+            <button
+                class={classes!("period-indicator", period, is_picked.then_some("picked"))}
+                aria-pressed={is_picked.to_string()}
+                onclick={on_period(afternoon)}
+            >
+                { text }
+            </button>
+        }
+    };
+
+    html! {
+        <div class="analog-clock-container clock-setter">
+            <svg
+                ref={svg_ref}
+                width={size.to_string()}
+                height={size.to_string()}
+                view_box={format!("0 0 {} {}", size, size)}
+                class="analog-clock-svg"
+                style={format!("--clock-viewbox-size: {};", size)}
+                onpointerdown={on_pointer_down}
+                onpointermove={on_pointer_move}
+                onpointerup={on_pointer_up.clone()}
+                onpointercancel={on_pointer_up}
+            >
+                { clock_face(center, radius) }
+                { hand_html(Hand::Hour, hour_hand_length) }
+                { hand_html(Hand::Minute, minute_hand_length) }
+                <circle class="center-dot" cx={center.to_string()} cy={center.to_string()} />
+            </svg>
+
+            <p class="clock-instruction">
+                {"Dra visarna, eller välj en visare med Tab och flytta den med piltangenterna."}
+            </p>
+            <div class="clock-period-choice">
+                { period_button(false, "Förmiddag") }
+                { period_button(true, "Eftermiddag") }
+            </div>
+            <button class="clock-submit-btn" onclick={on_submit}>{"Svara"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,249 @@
+use crate::api::{self, ApiError};
+use crate::components::DifficultySelect;
+use common::{
+    config::MAX_CUSTOM_QUIZ_QUESTIONS,
+    custom_quiz::{CustomQuiz, CustomQuizPart},
+    quiz::{Difficulty, Quiz, ALL_COURSES},
+    CreateCustomQuizRequest, User,
+};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+#[derive(Clone, PartialEq)]
+enum SaveState {
+    Idle,
+    Saving,
+    Saved(CustomQuiz),
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CustomQuizBuilderProps {
+    pub user: User,
+    pub on_created: Callback<CustomQuiz>,
+    pub on_cancel: Callback<()>,
+}
+
+// The link that opens a custom quiz directly, e.g. https://example.se/?quiz=K7XQ2M
+pub fn share_link(code: &str) -> String {
+    let location = gloo::utils::window().location();
+    format!(
+        "{}{}?quiz={}",
+        location.origin().unwrap_or_default(),
+        location.pathname().unwrap_or_default(),
+        code
+    )
+}
+
+// Form where a teacher picks quizzes and how many questions to take from each
+#[function_component(CustomQuizBuilder)]
+pub fn custom_quiz_builder(props: &CustomQuizBuilderProps) -> Html {
+    let title = use_state(String::new);
+    let difficulty = use_state(Difficulty::default);
+    let parts = use_state(|| vec![(Quiz::Area, 5)]);
+    let save_state = use_state(|| SaveState::Idle);
+
+    let total_questions: usize = parts.iter().map(|(_, questions)| questions).sum();
+
+    let on_title_input = {
+        let title = title.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            title.set(input.value());
+        })
+    };
+
+    let on_difficulty_change = {
+        let difficulty = difficulty.clone();
+        Callback::from(move |new_difficulty: Difficulty| {
+            difficulty.set(new_difficulty);
+        })
+    };
+
+    let on_add_part = {
+        let parts = parts.clone();
+        Callback::from(move |_| {
+            let mut new_parts = (*parts).clone();
+            new_parts.push((Quiz::Circumference, 5));
+            parts.set(new_parts);
+        })
+    };
+
+    let on_submit = {
+        let title = title.clone();
+        let difficulty = difficulty.clone();
+        let parts = parts.clone();
+        let save_state = save_state.clone();
+        let school_id = props.user.school_id;
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if *save_state == SaveState::Saving {
+                return;
+            }
+            if title.trim().is_empty() {
+                save_state.set(SaveState::Error("Ge quizen ett namn".into()));
+                return;
+            }
+            if parts.is_empty() || parts.iter().any(|(_, questions)| *questions == 0) {
+                save_state.set(SaveState::Error("Varje del behöver minst en fråga".into()));
+                return;
+            }
+            if parts.iter().map(|(_, questions)| questions).sum::<usize>()
+                > MAX_CUSTOM_QUIZ_QUESTIONS
+            {
+                save_state.set(SaveState::Error(format!(
+                    "En quiz kan ha högst {} frågor",
+                    MAX_CUSTOM_QUIZ_QUESTIONS
+                )));
+                return;
+            }
+
+            let req = CreateCustomQuizRequest {
+                title: title.trim().to_string(),
+                difficulty: *difficulty,
+                seed: None,
+                school_id,
+                parts: parts
+                    .iter()
+                    .map(|(quiz, questions)| CustomQuizPart {
+                        course: quiz.course_id(),
+                        questions: *questions,
+                    })
+                    .collect(),
+            };
+
+            save_state.set(SaveState::Saving);
+            let save_state = save_state.clone();
+            spawn_local(async move {
+                match api::create_custom_quiz(&req).await {
+                    Ok(custom_quiz) => save_state.set(SaveState::Saved(custom_quiz)),
+                    Err(ApiError::Server { status: 403, .. }) => {
+                        save_state.set(SaveState::Error("Skolans abonnemang har gått ut".into()));
+                    }
+                    Err(e) => {
+                        save_state.set(SaveState::Error(format!("Quizen kunde inte sparas: {}", e)))
+                    }
+                }
+            });
+        })
+    };
+
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_| on_cancel.emit(()))
+    };
+
+    if let SaveState::Saved(custom_quiz) = &*save_state {
+        let on_open = {
+            let on_created = props.on_created.clone();
+            let custom_quiz = custom_quiz.clone();
+            Callback::from(move |_| on_created.emit(custom_quiz.clone()))
+        };
+        return html! {
+            <div class="custom-quiz-builder">
+                <h2>{format!("{} är sparad", custom_quiz.title)}</h2>
+                <p>{"Ge eleverna koden eller länken:"}</p>
+                <p class="share-code">{&custom_quiz.code}</p>
+                <p class="share-link">{share_link(&custom_quiz.code)}</p>
+                <div class="start-actions">
+                    <button onclick={on_open}>{"Öppna quizen"}</button>
+                    <button class="practice-button" onclick={on_cancel}>{"Tillbaka"}</button>
+                </div>
+            </div>
+        };
+    }
+
+    let saving = *save_state == SaveState::Saving;
+
+    html! {
+        <div class="custom-quiz-builder">
+            <h2>{"Skapa egen quiz"}</h2>
+            <form onsubmit={on_submit}>
+                <input
+                    type="text"
+                    class="custom-quiz-title"
+                    placeholder="Namn, t.ex. Geometri vecka 12"
+                    value={(*title).clone()}
+                    oninput={on_title_input}
+                />
+                <DifficultySelect selected={*difficulty} on_change={on_difficulty_change} />
+                <ul class="custom-quiz-parts">
+                    {
+                        parts.iter().enumerate().map(|(index, (quiz, questions))| {
+                            let on_quiz_change = {
+                                let parts = parts.clone();
+                                Callback::from(move |e: Event| {
+                                    let select: HtmlSelectElement = e.target_unchecked_into();
+                                    if let Some(quiz) = Quiz::from_course_id(&select.value()) {
+                                        let mut new_parts = (*parts).clone();
+                                        new_parts[index].0 = quiz;
+                                        parts.set(new_parts);
+                                    }
+                                })
+                            };
+                            let on_questions_input = {
+                                let parts = parts.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                    let mut new_parts = (*parts).clone();
+                                    new_parts[index].1 = input.value().parse().unwrap_or(0);
+                                    parts.set(new_parts);
+                                })
+                            };
+                            let on_remove = {
+                                let parts = parts.clone();
+                                Callback::from(move |_| {
+                                    let mut new_parts = (*parts).clone();
+                                    new_parts.remove(index);
+                                    parts.set(new_parts);
+                                })
+                            };
+
+                            html! {
+                                <li key={index}>
+                                    <select onchange={on_quiz_change}>
+                                        {
+                                            ALL_COURSES.iter().skip(1).map(|course| html! {
+                                                <option
+                                                    value={course.course_id()}
+                                                    selected={course == quiz}
+                                                >
+                                                    {course.to_string()}
+                                                </option>
+                                            }).collect::<Html>()
+                                        }
+                                    </select>
+                                    <input
+                                        type="number"
+                                        min="1"
+                                        max={MAX_CUSTOM_QUIZ_QUESTIONS.to_string()}
+                                        value={questions.to_string()}
+                                        oninput={on_questions_input}
+                                    />
+                                    <button type="button" onclick={on_remove}>{"Ta bort"}</button>
+                                </li>
+                            }
+                        }).collect::<Html>()
+                    }
+                </ul>
+                <button type="button" class="add-part" onclick={on_add_part}>{"Lägg till quiz"}</button>
+                <p>{format!("{} frågor totalt (högst {})", total_questions, MAX_CUSTOM_QUIZ_QUESTIONS)}</p>
+                {
+                    match &*save_state {
+                        SaveState::Error(msg) => html!{ <p class="status-message error">{msg}</p> },
+                        _ => html!{}
+                    }
+                }
+                <div class="start-actions">
+                    <button type="submit" disabled={saving}>
+                        { if saving { "Sparar..." } else { "Spara" } }
+                    </button>
+                    <button type="button" class="practice-button" onclick={on_cancel}>{"Avbryt"}</button>
+                </div>
+            </form>
+        </div>
+    }
+}
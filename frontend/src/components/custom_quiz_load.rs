@@ -0,0 +1,99 @@
+use crate::api::{self, ApiError};
+use common::custom_quiz::CustomQuiz;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Clone, PartialEq)]
+enum LoadState {
+    Idle,
+    Loading,
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CustomQuizLoadProps {
+    pub on_load: Callback<CustomQuiz>,
+}
+
+// Form where a student types the code the teacher handed out
+#[function_component(CustomQuizLoad)]
+pub fn custom_quiz_load(props: &CustomQuizLoadProps) -> Html {
+    let code = use_state(String::new);
+    let load_state = use_state(|| LoadState::Idle);
+
+    let on_input = {
+        let code = code.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            code.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let code = code.clone();
+        let load_state = load_state.clone();
+        let on_load = props.on_load.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if *load_state == LoadState::Loading {
+                return;
+            }
+            let quiz_code = (*code).trim().to_uppercase();
+            if quiz_code.is_empty() {
+                load_state.set(LoadState::Error("Ange quizens kod".into()));
+                return;
+            }
+
+            load_state.set(LoadState::Loading);
+            let code = code.clone();
+            let load_state = load_state.clone();
+            let on_load = on_load.clone();
+
+            spawn_local(async move {
+                match api::fetch_custom_quiz(&quiz_code).await {
+                    Ok(custom_quiz) => {
+                        code.set(String::new());
+                        load_state.set(LoadState::Idle);
+                        on_load.emit(custom_quiz);
+                    }
+                    Err(ApiError::Server { status: 404, .. }) => {
+                        load_state.set(LoadState::Error("Okänd kod".into()));
+                    }
+                    Err(e) => {
+                        load_state
+                            .set(LoadState::Error(format!("Quizen kunde inte laddas: {}", e)));
+                    }
+                }
+            });
+        })
+    };
+
+    let loading = *load_state == LoadState::Loading;
+
+    html! {
+        <div class="custom-quiz-load">
+            <h4>{"Quiz från läraren"}</h4>
+            <form onsubmit={on_submit}>
+                <input
+                    type="text"
+                    placeholder="Kod, t.ex. K7XQ2M"
+                    value={(*code).clone()}
+                    oninput={on_input}
+                    disabled={loading}
+                />
+                <button type="submit" disabled={loading}>
+                    { if loading { "Laddar..." } else { "Öppna" } }
+                </button>
+            </form>
+            {
+                match &*load_state {
+                    LoadState::Error(msg) => html!{ <p class="status-message error">{msg}</p> },
+                    _ => html!{}
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,38 @@
+use common::quiz::Difficulty;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct DifficultySelectProps {
+    pub selected: Difficulty,
+    pub on_change: Callback<Difficulty>,
+}
+
+// Row of buttons for choosing how hard the questions are
+#[function_component(DifficultySelect)]
+pub fn difficulty_select(props: &DifficultySelectProps) -> Html {
+    html! {
+        <div class="difficulty-select">
+            {
+                Difficulty::ALL.iter().map(|difficulty| {
+                    let on_click = {
+                        let on_change = props.on_change.clone();
+                        let difficulty = *difficulty;
+                        Callback::from(move |_| {
+                            on_change.emit(difficulty);
+                        })
+                    };
+                    let is_selected = props.selected == *difficulty;
+
+                    html! {
+                        <button
+                            class={classes!(is_selected.then_some("selected"))}
+                            onclick={on_click}
+                        >
+                            {difficulty.to_string()}
+                        </button>
+                    }
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
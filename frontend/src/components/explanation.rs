@@ -0,0 +1,23 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ExplanationProps {
+    pub steps: Vec<String>,
+}
+
+// The worked steps from a question to its answer, shown after a wrong answer
+#[function_component(Explanation)]
+pub fn explanation(props: &ExplanationProps) -> Html {
+    if props.steps.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="explanation">
+            <strong>{"Så här räknar du:"}</strong>
+            <ol>
+                { for props.steps.iter().map(|step| html! { <li>{ step }</li> }) }
+            </ol>
+        </div>
+    }
+}
@@ -1,6 +1,9 @@
 // frontend/src/components/leaderboard.rs
 use crate::api::{self, ApiError}; // Import the api module and error type
-use common::{config::MAX_ENTRIES_PER_COURSE, LeaderboardEntry, SubmitScoreRequest, User};
+use common::{
+    config::MAX_ENTRIES_PER_COURSE, quiz::Difficulty, LeaderboardEntry, SubmitScoreRequest, User,
+};
+use uuid::Uuid;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
@@ -26,25 +29,30 @@ enum SubmitState {
 #[derive(Properties, PartialEq, Clone)]
 pub struct LeaderboardProps {
     pub course: String,
+    pub title: String,          // Shown in the heading instead of the course id
+    pub difficulty: Difficulty, // Each difficulty has its own leaderboard
     pub user: User,
     pub allow_submission: bool,
     pub user_time: Option<f64>,
+    pub session_id: Option<Uuid>, // Finished session that backs user_time
 }
 
 #[function_component(Leaderboard)]
 pub fn leaderboard(props: &LeaderboardProps) -> Html {
     let fetch_state = use_state(|| FetchState::Idle);
-    let player_name = use_state(String::new);
+    // The picked student's name, which can still be changed before submitting
+    let player_name = use_state(|| props.user.name.clone());
     let submit_state = use_state(|| SubmitState::Idle);
 
     // Ensure this is the version you have:
     {
         let fetch_state = fetch_state.clone();
         let course = props.course.clone();
+        let difficulty = props.difficulty;
         let user = props.user.clone(); // User prop contains school/school_id
 
-        // *** Effect depends on course AND user ***
-        use_effect_with((course.clone(), user.clone()), move |_| {
+        // *** Effect depends on course, difficulty AND user ***
+        use_effect_with((course.clone(), difficulty, user.clone()), move |_| {
             if course == "No Course" {
                 fetch_state.set(FetchState::Success(Vec::new()));
             } else {
@@ -55,7 +63,7 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
 
                 spawn_local(async move {
                     // *** Call fetch_leaderboard with all 3 arguments ***
-                    match api::fetch_leaderboard(&course, &school, &school_id).await {
+                    match api::fetch_leaderboard(&course, difficulty, &school, &school_id).await {
                         Ok(data) => fetch_state.set(FetchState::Success(data)),
                         Err(e) => {
                             fetch_state.set(FetchState::Error(format!("Kunde inte ladda: {}", e)))
@@ -67,16 +75,16 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
         });
     }
 
-    // Effect for resetting submission state when course or user_time changes
+    // Effect for resetting submission state when course, user_time or the student changes
     {
         let submit_state = submit_state.clone();
+        let player_name = player_name.clone();
         let user_time = props.user_time;
 
         use_effect_with(
             (props.course.clone(), user_time, props.user.name.clone()),
-            move |_| {
-                // Consider pre-filling name again if user changes, or keep empty
-                // player_name.set(initial_name); // Or player_name.set(String::new());
+            move |(_, _, name)| {
+                player_name.set(name.clone());
                 submit_state.set(SubmitState::Idle);
                 || ()
             },
@@ -95,6 +103,7 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
     let refresh_leaderboard = {
         let fetch_state = fetch_state.clone();
         let course = props.course.clone();
+        let difficulty = props.difficulty;
         let user = props.user.clone(); // Need user info for refresh as well
         Callback::from(move |_| {
             if course != "No Course" {
@@ -105,7 +114,7 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
                 fetch_state.set(FetchState::Loading);
                 spawn_local(async move {
                     // Refresh using the updated fetch_leaderboard call
-                    match api::fetch_leaderboard(&course, &school, &school_id).await {
+                    match api::fetch_leaderboard(&course, difficulty, &school, &school_id).await {
                         Ok(data) => fetch_state.set(FetchState::Success(data)),
                         Err(e) => fetch_state
                             .set(FetchState::Error(format!("Kunde inte ladda om: {}", e))),
@@ -120,6 +129,7 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
         let submit_state_handle = submit_state.clone();
         let course = props.course.clone();
         let user_time = props.user_time;
+        let session_id = props.session_id;
         let refresh_leaderboard = refresh_leaderboard.clone();
         // Get user details directly from props inside the callback closure
         let user = props.user.clone();
@@ -144,9 +154,9 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
                 return;
             }
 
-            let time = match user_time {
-                Some(t) => t,
-                None => {
+            let session_id = match (user_time, session_id) {
+                (Some(_), Some(session_id)) => session_id,
+                _ => {
                     submit_state_handle.set(SubmitState::Error("Ingen tid finns".into()));
                     return;
                 }
@@ -159,7 +169,8 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
                 course: course.clone(),
                 school: user.school.clone(), // Use school from user prop
                 school_id: user.school_id,   // Use school_id from user prop
-                time_seconds: time,
+                student_id: user.student_id, // Keys the student's own best times
+                session_id,                  // The server takes the time from this session
             };
 
             let submit_state = submit_state_handle.clone();
@@ -245,7 +256,7 @@ pub fn leaderboard(props: &LeaderboardProps) -> Html {
         );
     html! {
         <div class="leaderboard-container">
-        <h2>{format!("Topplista: {}", props.course)}</h2>
+        <h2>{format!("Topplista: {} ({})", props.title, props.difficulty)}</h2>
 
             // Submit score form
             if show_submit_form {
@@ -12,3 +12,31 @@ pub mod top_users;
 pub use top_users::*;
 pub mod analog_clock;
 pub use analog_clock::*;
+pub mod clock_setter;
+pub use clock_setter::*;
+pub mod clock_faces;
+pub use clock_faces::*;
+pub mod timetable;
+pub use timetable::*;
+pub mod school_access;
+pub use school_access::*;
+pub mod student_picker;
+pub use student_picker::*;
+pub mod difficulty_select;
+pub use difficulty_select::*;
+pub mod explanation;
+pub use explanation::*;
+pub mod practice_feedback;
+pub use practice_feedback::*;
+pub mod practice_result;
+pub use practice_result::*;
+pub mod custom_quiz_load;
+pub use custom_quiz_load::*;
+pub mod custom_quiz_builder;
+pub use custom_quiz_builder::*;
+pub mod shape_figure;
+pub use shape_figure::*;
+pub mod solid_drawing;
+pub use solid_drawing::*;
+pub mod multiple_choice_quiz;
+pub use multiple_choice_quiz::*;
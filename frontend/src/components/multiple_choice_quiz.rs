@@ -0,0 +1,70 @@
+use common::quiz::Choice;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct MultipleChoiceQuizProps {
+    pub prompt: String,
+    pub choices: Vec<Choice>,
+    pub on_answer: Callback<String>,
+}
+
+// Choice buttons that are toggled on and off, answered with the values of the
+// picked ones separated by commas
+#[function_component(MultipleChoiceQuiz)]
+pub fn multiple_choice_quiz(props: &MultipleChoiceQuizProps) -> Html {
+    let picked = use_state(Vec::<String>::new);
+
+    // Start with nothing picked when the next question comes
+    {
+        let picked = picked.clone();
+        use_effect_with(props.prompt.clone(), move |_| {
+            picked.set(Vec::new());
+        });
+    }
+
+    let on_submit = {
+        let picked = picked.clone();
+        let on_answer = props.on_answer.clone();
+        Callback::from(move |_| {
+            on_answer.emit(picked.join(","));
+            picked.set(Vec::new());
+        })
+    };
+
+    html! {
+        <div class="choice-button-quiz-container multiple-choice-quiz">
+            <h2>{ props.prompt.clone() }</h2>
+
+            <div class="choice-options">
+                {
+                    props.choices.iter().map(|choice| {
+                        let is_picked = picked.contains(&choice.value);
+                        let on_toggle = {
+                            let picked = picked.clone();
+                            let value = choice.value.clone();
+                            Callback::from(move |_| {
+                                let mut new_picked = (*picked).clone();
+                                if let Some(position) = new_picked.iter().position(|v| *v == value) {
+                                    new_picked.remove(position);
+                                } else {
+                                    new_picked.push(value.clone());
+                                }
+                                picked.set(new_picked);
+                            })
+                        };
+                        html! {
+                            <button
+                                class={classes!("choice-option-button", is_picked.then_some("picked"))}
+                                aria-pressed={is_picked.to_string()}
+                                onclick={on_toggle}
+                            >
+                                { &choice.display_text }
+                            </button>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            <button class="multiple-choice-submit" onclick={on_submit}>{"Svara"}</button>
+        </div>
+    }
+}
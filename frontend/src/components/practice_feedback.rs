@@ -0,0 +1,64 @@
+use common::quiz::{Question, QuestionBox, QuestionView};
+use yew::prelude::*;
+
+use super::{ClockFaces, Explanation, ShapeFigure, SolidDrawing, Timetable};
+
+// Choice questions are answered with the values of buttons, so show their texts instead
+pub fn shown_answer(question: &QuestionBox, user_answer: &str) -> String {
+    match question.view() {
+        QuestionView::Choices(choices) => choices
+            .into_iter()
+            .find(|choice| choice.value == user_answer)
+            .map(|choice| choice.display_text)
+            .unwrap_or_else(|| user_answer.to_string()),
+        QuestionView::MultipleChoices(choices) => {
+            let picked: Vec<String> = choices
+                .into_iter()
+                .filter(|choice| user_answer.split(',').any(|value| value == choice.value))
+                .map(|choice| choice.display_text)
+                .collect();
+            if picked.is_empty() {
+                "Inget valt".to_string()
+            } else {
+                picked.join(", ")
+            }
+        }
+        _ => user_answer.to_string(),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PracticeFeedbackProps {
+    pub question: QuestionBox,
+    pub user_answer: String,
+    pub on_next: Callback<()>,
+}
+
+// Shown in practice mode after a wrong answer, before moving on to the next question
+#[function_component(PracticeFeedback)]
+pub fn practice_feedback(props: &PracticeFeedbackProps) -> Html {
+    let on_next = {
+        let on_next = props.on_next.clone();
+        Callback::from(move |_| {
+            on_next.emit(());
+        })
+    };
+
+    html! {
+        <div class="practice-feedback">
+            <h2>{"Inte riktigt!"}</h2>
+            <p><strong>{"Fråga:"}</strong><br/>{ props.question.prompt() }</p>
+            { match props.question.view() {
+                QuestionView::Figure(figure) => html! { <ShapeFigure figure={figure} size={160.0} /> },
+                QuestionView::Solid(question) => html! { <SolidDrawing question={question.clone()} size={140} /> },
+                QuestionView::Clocks(clocks) => html! { <ClockFaces clocks={clocks} size={120} /> },
+                QuestionView::Timetable(rows) => html! { <Timetable rows={rows} /> },
+                _ => html! {},
+            } }
+            <p><strong>{"Ditt svar:"}</strong><br/><span class="wrong-answer">{ shown_answer(&props.question, &props.user_answer) }</span></p>
+            <p><strong>{"Rätt svar:"}</strong><br/><span class="correct-answer">{ props.question.answer().to_string() }</span></p>
+            <Explanation steps={props.question.explanation()} />
+            <button onclick={on_next}>{"Nästa fråga"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,70 @@
+use common::quiz::{Question, QuestionBox};
+use yew::prelude::*;
+
+use super::practice_feedback::shown_answer;
+
+#[derive(Properties, PartialEq)]
+pub struct PracticeResultProps {
+    pub total_questions: usize,
+    pub mistakes: Vec<(QuestionBox, String)>,
+    pub on_retry_missed: Callback<()>,
+    pub on_restart: Callback<()>,
+}
+
+// End screen of a practice run: accuracy and every mistake that was made
+#[function_component(PracticeResult)]
+pub fn practice_result(props: &PracticeResultProps) -> Html {
+    let correct = props.total_questions.saturating_sub(props.mistakes.len());
+    let accuracy = if props.total_questions > 0 {
+        correct as f32 / props.total_questions as f32 * 100.0
+    } else {
+        0.0
+    };
+
+    let on_retry_missed = {
+        let on_retry_missed = props.on_retry_missed.clone();
+        Callback::from(move |_| {
+            on_retry_missed.emit(());
+        })
+    };
+
+    let on_restart = {
+        let on_restart = props.on_restart.clone();
+        Callback::from(move |_| {
+            on_restart.emit(());
+        })
+    };
+
+    html! {
+        <div class="practice-result">
+            <h2>{format!("{} av {} rätt ({:.0}%)", correct, props.total_questions, accuracy)}</h2>
+            {
+                if props.mistakes.is_empty() {
+                    html! { <p>{"Inga misstag, snyggt jobbat!"}</p> }
+                } else {
+                    html! {
+                        <ul class="mistake-list">
+                            {
+                                props.mistakes.iter().map(|(question, user_answer)| html! {
+                                    <li>
+                                        <p>{ question.prompt() }</p>
+                                        <p>
+                                            {"Ditt svar: "}<span class="wrong-answer">{ shown_answer(question, user_answer) }</span>
+                                            {" · Rätt svar: "}<span class="correct-answer">{ question.answer().to_string() }</span>
+                                        </p>
+                                    </li>
+                                }).collect::<Html>()
+                            }
+                        </ul>
+                    }
+                }
+            }
+            <div class="result-actions">
+                if !props.mistakes.is_empty() {
+                    <button onclick={on_retry_missed}>{"Öva på missade frågor"}</button>
+                }
+                <button onclick={on_restart}>{"Tillbaka"}</button>
+            </div>
+        </div>
+    }
+}
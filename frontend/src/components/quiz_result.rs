@@ -1,7 +1,9 @@
-use crate::quiz::{Question, QuestionBox, Quiz};
+use common::quiz::{Question, QuestionBox, QuestionView, Quiz};
 use web_time::Duration;
 use yew::prelude::*;
 
+use super::{shown_answer, ClockFaces, Explanation, ShapeFigure, SolidDrawing, Timetable};
+
 #[derive(Properties, PartialEq)]
 pub struct ResultSectionProps {
     pub passed: bool,
@@ -38,8 +40,16 @@ pub fn result(props: &ResultSectionProps) -> Html {
                     html! {
                         <div class="failure-details">
                             <p><strong>{"Fråga:"}</strong><br/>{ failed_question.prompt() }</p>
-                            <p><strong>{"Ditt svar:"}</strong><br/><span style="color: red;">{ user_answer }</span></p>
-                            <p><strong>{"Rätt svar:"}</strong><br/><span style="color: green;">{ failed_question.answer() }</span></p>
+                            { match failed_question.view() {
+                                QuestionView::Figure(figure) => html! { <ShapeFigure figure={figure} size={160.0} /> },
+                                QuestionView::Solid(question) => html! { <SolidDrawing question={question.clone()} size={140} /> },
+                                QuestionView::Clocks(clocks) => html! { <ClockFaces clocks={clocks} size={120} /> },
+                                QuestionView::Timetable(rows) => html! { <Timetable rows={rows} /> },
+                                _ => html! {},
+                            } }
+                            <p><strong>{"Ditt svar:"}</strong><br/><span style="color: red;">{ shown_answer(failed_question, user_answer) }</span></p>
+                            <p><strong>{"Rätt svar:"}</strong><br/><span style="color: green;">{ failed_question.answer().to_string() }</span></p>
+                            <Explanation steps={failed_question.explanation()} />
                         </div>
                     }
                 } else {
@@ -1,14 +1,7 @@
-use crate::quiz::{Quiz, Subject};
+use common::quiz::{Quiz, Subject};
 use std::{collections::BTreeMap, rc::Rc};
 use yew::prelude::*;
 
-#[derive(Properties, PartialEq)]
-struct EnumListSelectorProps {
-    pub options: Rc<Vec<Quiz>>,
-    pub selected: Quiz,
-    pub on_change: Callback<Quiz>,
-}
-
 #[derive(Properties, PartialEq)]
 pub struct QuizSelectionProps {
     pub options: Rc<Vec<Quiz>>,
@@ -1,8 +1,8 @@
-use crate::{
-    components::{AnalogClock, ChoiceButtonQuizComponent},
-    quiz::{Question, QuestionBox},
-    quizzes::{FractionComparisonQuestion, NumberComparisonQuestion, RomanNumeralsQuestion},
+use crate::components::{
+    AnalogClock, ChoiceButtonQuizComponent, ClockFaces, ClockSetter, MultipleChoiceQuiz,
+    ShapeFigure, SolidDrawing, Timetable,
 };
+use common::quiz::{Question, QuestionBox, QuestionView};
 use web_time::Duration;
 use yew::prelude::*;
 
@@ -59,25 +59,32 @@ pub fn quiz_session(props: &QuizSectionProps) -> Html {
             </div>
 
             {
-                // Choose which component to render based on the question type
-                match &props.question {
-                    QuestionBox::NumberComparison(question) => {
+                // Choose which component to render based on the question's view
+                match props.question.view() {
+                    QuestionView::Choices(choices) => {
                         html! {
-                            <ChoiceButtonQuizComponent<NumberComparisonQuestion>
-                                question={question.clone()}
+                            <ChoiceButtonQuizComponent
+                                prompt={props.question.display()}
+                                choices={choices}
                                 on_answer={props.on_answer.clone()}
                             />
                         }
                     },
-                    QuestionBox::FractionComparison(question) => {
+                    QuestionView::MultipleChoices(choices) => {
                         html! {
-                            <ChoiceButtonQuizComponent<FractionComparisonQuestion>
-                                question={question.clone()}
+                            <MultipleChoiceQuiz
+                                prompt={props.question.display()}
+                                choices={choices}
                                 on_answer={props.on_answer.clone()}
                             />
                         }
                     },
-                    QuestionBox::ClockReading(question) => {
+                    QuestionView::Clock(question) => {
+                        let (instruction, placeholder) = if question.answers_in_words() {
+                            ("Skriv tiden med ord (t.ex. kvart över tolv eller fem i halv tre)", "kvart över tolv")
+                        } else {
+                            ("Skriv tiden i format TT:MM eller TTMM (t.ex. 12:15 eller 1215), eller med ord (t.ex. kvart över tolv)", "TT:MM")
+                        };
                         html! {
                             <div class="clock-question">
                                 <h2>{props.question.display()}</h2>
@@ -85,14 +92,14 @@ pub fn quiz_session(props: &QuizSectionProps) -> Html {
                                     <AnalogClock question={question.clone()} size={250} />
                                 </div>
                                 <div class="clock-input-section">
-                                    <p class="clock-instruction">{"Skriv tiden i format TT:MM eller TTMM (t.ex. 12:15 eller 1215)"}</p>
+                                    <p class="clock-instruction">{instruction}</p>
                                     <form onsubmit={on_submit}>
                                         <input
                                             type="text"
                                             ref={input_ref}
                                             value={(*answer).clone()}
                                             oninput={on_input}
-                                            placeholder="TT:MM"
+                                            placeholder={placeholder}
                                             // pattern="[0-9]{1,2}:[0-9]{2}"
                                             class="clock-time-input"
                                         />
@@ -102,15 +109,99 @@ pub fn quiz_session(props: &QuizSectionProps) -> Html {
                             </div>
                         }
                     },
-                     QuestionBox::RomanNumerals(question) => {
+                    QuestionView::SetClock(_) => {
                         html! {
-                            <ChoiceButtonQuizComponent<RomanNumeralsQuestion>
-                                question={question.clone()}
-                                on_answer={props.on_answer.clone()}
-                            />
+                            <div class="clock-question">
+                                <h2>{props.question.display()}</h2>
+                                <ClockSetter
+                                    prompt={props.question.display()}
+                                    on_answer={props.on_answer.clone()}
+                                    size={250}
+                                />
+                            </div>
+                        }
+                    },
+                    QuestionView::Clocks(clocks) => {
+                        html! {
+                            <div class="question figure-question">
+                                <h2>{props.question.display()}</h2>
+                                <div class="figure-display">
+                                    <ClockFaces clocks={clocks} size={180} />
+                                </div>
+                                <form onsubmit={on_submit}>
+                                    <input
+                                        type="text"
+                                        ref={input_ref}
+                                        value={(*answer).clone()}
+                                        oninput={on_input}
+                                        placeholder="Ange ditt svar"
+                                    />
+                                    <button type="submit">{"Submit"}</button>
+                                </form>
+                            </div>
+                        }
+                    },
+                    QuestionView::Timetable(rows) => {
+                        html! {
+                            <div class="question figure-question">
+                                <h2>{props.question.display()}</h2>
+                                <div class="figure-display">
+                                    <Timetable rows={rows} />
+                                </div>
+                                <form onsubmit={on_submit}>
+                                    <input
+                                        type="text"
+                                        ref={input_ref}
+                                        value={(*answer).clone()}
+                                        oninput={on_input}
+                                        placeholder="Ange ditt svar"
+                                    />
+                                    <button type="submit">{"Submit"}</button>
+                                </form>
+                            </div>
+                        }
+                    },
+                    QuestionView::Figure(figure) => {
+                        html! {
+                            <div class="question figure-question">
+                                <h2>{props.question.display()}</h2>
+                                <div class="figure-display">
+                                    <ShapeFigure figure={figure} />
+                                </div>
+                                <form onsubmit={on_submit}>
+                                    <input
+                                        type="text"
+                                        ref={input_ref}
+                                        value={(*answer).clone()}
+                                        oninput={on_input}
+                                        placeholder="Ange ditt svar"
+                                    />
+                                    <button type="submit">{"Submit"}</button>
+                                </form>
+                            </div>
+                        }
+                    },
+                    QuestionView::Solid(question) => {
+                        html! {
+                            <div class="question figure-question">
+                                <h2>{props.question.display()}</h2>
+                                <div class="figure-display">
+                                    <SolidDrawing question={question.clone()} size={220} />
+                                </div>
+                                <form onsubmit={on_submit}>
+                                    <input
+                                        type="text"
+                                        ref={input_ref}
+                                        value={(*answer).clone()}
+                                        oninput={on_input}
+                                        placeholder="Ange ditt svar"
+                                    />
+                                    <button type="submit">{"Submit"}</button>
+                                </form>
+                            </div>
                         }
                     },
-                    _ => {
+                    QuestionView::Text => {
                         html! {
                             <div class="question">
                                 <h2>{props.question.display()}</h2>
@@ -0,0 +1,102 @@
+use crate::api::{self, ApiError};
+use common::School;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Clone, PartialEq)]
+enum RedeemState {
+    Idle,
+    Checking,
+    Error(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SchoolAccessProps {
+    pub on_school: Callback<School>,
+}
+
+#[function_component(SchoolAccess)]
+pub fn school_access(props: &SchoolAccessProps) -> Html {
+    let access_code = use_state(String::new);
+    let redeem_state = use_state(|| RedeemState::Idle);
+
+    let on_input = {
+        let access_code = access_code.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            access_code.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let access_code = access_code.clone();
+        let redeem_state = redeem_state.clone();
+        let on_school = props.on_school.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if *redeem_state == RedeemState::Checking {
+                return;
+            }
+            let code = (*access_code).trim().to_string();
+            if code.is_empty() {
+                redeem_state.set(RedeemState::Error("Ange skolans kod".into()));
+                return;
+            }
+
+            redeem_state.set(RedeemState::Checking);
+            let redeem_state = redeem_state.clone();
+            let on_school = on_school.clone();
+
+            spawn_local(async move {
+                match api::redeem_access_code(&code).await {
+                    Ok(school) => {
+                        redeem_state.set(RedeemState::Idle);
+                        on_school.emit(school);
+                    }
+                    Err(ApiError::Server { status: 404, .. }) => {
+                        redeem_state.set(RedeemState::Error("Okänd kod".into()));
+                    }
+                    Err(ApiError::Server { status: 403, .. }) => {
+                        redeem_state
+                            .set(RedeemState::Error("Skolans abonnemang har gått ut".into()));
+                    }
+                    Err(e) => {
+                        redeem_state.set(RedeemState::Error(format!(
+                            "Koden kunde inte kontrolleras: {}",
+                            e
+                        )));
+                    }
+                }
+            });
+        })
+    };
+
+    let checking = *redeem_state == RedeemState::Checking;
+
+    html! {
+        <div class="school-access">
+            <h4>{"Logga in med skolans kod"}</h4>
+            <form onsubmit={on_submit}>
+                <input
+                    type="text"
+                    placeholder="t.ex. MinSkola2024"
+                    value={(*access_code).clone()}
+                    oninput={on_input}
+                    disabled={checking}
+                />
+                <button type="submit" disabled={checking}>
+                    { if checking { "Kontrollerar..." } else { "Logga in" } }
+                </button>
+            </form>
+            {
+                match &*redeem_state {
+                    RedeemState::Error(msg) => html!{ <p class="status-message error">{msg}</p> },
+                    _ => html!{}
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,103 @@
+use common::figure::Figure;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ShapeFigureProps {
+    pub figure: Figure,
+    #[prop_or(260.0)]
+    pub size: f64,
+}
+
+// Room around the figure for the side labels, in pixels
+const MARGIN: f64 = 50.0;
+// How far a label sits outside its side
+const LABEL_OFFSET: f64 = 10.0;
+
+// Draws a figure scaled to fit, with each label written just outside its side
+#[function_component(ShapeFigure)]
+pub fn shape_figure(props: &ShapeFigureProps) -> Html {
+    let corners = &props.figure.corners;
+    if corners.is_empty() {
+        return html! {};
+    }
+
+    let min_x = corners.iter().map(|c| c.0).fold(f64::INFINITY, f64::min);
+    let max_x = corners
+        .iter()
+        .map(|c| c.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = corners.iter().map(|c| c.1).fold(f64::INFINITY, f64::min);
+    let max_y = corners
+        .iter()
+        .map(|c| c.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let scale = props.size / (max_x - min_x).max(max_y - min_y).max(f64::EPSILON);
+    let width = (max_x - min_x) * scale + 2.0 * MARGIN;
+    let height = (max_y - min_y) * scale + 2.0 * MARGIN;
+
+    let points: Vec<(f64, f64)> = corners
+        .iter()
+        .map(|(x, y)| ((x - min_x) * scale + MARGIN, (y - min_y) * scale + MARGIN))
+        .collect();
+    let outline = points
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // The sign of the area tells which side of each edge is outside the figure
+    let winding = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<f64>()
+        .signum();
+
+    let labels = props
+        .figure
+        .side_labels
+        .iter()
+        .enumerate()
+        .filter_map(|(i, label)| {
+            let label = label.as_ref()?;
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[(i + 1) % points.len()];
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+            let (nx, ny) = (winding * dy / length, -winding * dx / length);
+            let x = (x1 + x2) / 2.0 + nx * LABEL_OFFSET;
+            let y = (y1 + y2) / 2.0 + ny * LABEL_OFFSET;
+            // Labels grow away from the side, so long ones don't cross it
+            let anchor = if nx > 0.5 {
+                "start"
+            } else if nx < -0.5 {
+                "end"
+            } else {
+                "middle"
+            };
+            Some(html! {
+                <text
+                    x={format!("{:.1}", x)}
+                    y={format!("{:.1}", y)}
+                    class="figure-label"
+                    text-anchor={anchor}
+                    dominant-baseline="middle"
+                >
+                    {label}
+                </text>
+            })
+        })
+        .collect::<Html>();
+
+    html! {
+        <svg
+            class="shape-figure"
+            width={format!("{:.0}", width)}
+            height={format!("{:.0}", height)}
+            viewBox={format!("0 0 {:.1} {:.1}", width, height)}
+        >
+            <polygon points={outline} class="figure-outline" />
+            {labels}
+        </svg>
+    }
+}
@@ -0,0 +1,463 @@
+use common::quizzes::{Solid, SolidQuestion};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SolidDrawingProps {
+    pub question: SolidQuestion,
+    pub size: Option<u32>, // Optional size in pixels
+}
+
+// Room around the drawing for the labels, in pixels
+const MARGIN: f64 = 50.0;
+// How far a label sits from the line it measures
+const LABEL_OFFSET: f64 = 8.0;
+
+type Point = (f64, f64);
+
+enum Part {
+    // class is one of solid-edge, solid-hidden (edges behind the solid) and
+    // solid-measure (heights and radii drawn inside it)
+    Line {
+        from: Point,
+        to: Point,
+        class: &'static str,
+    },
+    // A horizontal circle, of which the back half is hidden unless it is on top
+    Ellipse {
+        center: Point,
+        radius: f64,
+        half: Half,
+    },
+    Circle {
+        center: Point,
+        radius: f64,
+    },
+}
+
+enum Half {
+    Whole,
+    Front,
+    Back,
+}
+
+struct Label {
+    at: Point,
+    // Which way the label is pushed away from its line
+    direction: Point,
+    text: String,
+}
+
+// Isometric projection onto the screen, with the z axis pointing up
+fn project(x: f64, y: f64, z: f64) -> Point {
+    ((x - y) * 3f64.sqrt() / 2.0, (x + y) / 2.0 - z)
+}
+
+// The half axes of a horizontal circle in the same projection
+fn ellipse_axes(radius: f64) -> (f64, f64) {
+    (radius * 1.5f64.sqrt(), radius * 0.5f64.sqrt())
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+fn edge(from: Point, to: Point) -> Part {
+    Part::Line {
+        from,
+        to,
+        class: "solid-edge",
+    }
+}
+
+fn hidden(from: Point, to: Point) -> Part {
+    Part::Line {
+        from,
+        to,
+        class: "solid-hidden",
+    }
+}
+
+fn measure(from: Point, to: Point) -> Part {
+    Part::Line {
+        from,
+        to,
+        class: "solid-measure",
+    }
+}
+
+// A label at the middle of a line, on the side facing away from `inside`
+fn line_label(from: Point, to: Point, inside: Point, text: String) -> Label {
+    let at = midpoint(from, to);
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = (dx * dx + dy * dy).sqrt().max(f64::EPSILON);
+    let (mut nx, mut ny) = (dy / length, -dx / length);
+    if nx * (at.0 - inside.0) + ny * (at.1 - inside.1) < 0.0 {
+        (nx, ny) = (-nx, -ny);
+    }
+    Label {
+        at,
+        direction: (nx, ny),
+        text,
+    }
+}
+
+// The lines and labels of the solid, in projected length units
+fn drawing(question: &SolidQuestion) -> (Vec<Part>, Vec<Label>) {
+    let surface_area = question.asks_for_surface_area();
+    match *question.solid() {
+        Solid::Cube { side } => cuboid(question, side, side, side, true),
+        Solid::Cuboid {
+            length,
+            width,
+            height,
+        } => cuboid(question, length, width, height, false),
+        Solid::Cylinder { radius, height } => {
+            let (r, h) = (radius as f64, height as f64);
+            let (ex, _) = ellipse_axes(r);
+            let top = (0.0, -h);
+            let parts = vec![
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: r,
+                    half: Half::Back,
+                },
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: r,
+                    half: Half::Front,
+                },
+                Part::Ellipse {
+                    center: top,
+                    radius: r,
+                    half: Half::Whole,
+                },
+                edge((-ex, 0.0), (-ex, -h)),
+                edge((ex, 0.0), (ex, -h)),
+                measure(top, (ex, -h)),
+            ];
+            let labels = vec![
+                line_label(
+                    top,
+                    (ex, -h),
+                    (ex / 2.0, 1.0 - h),
+                    question.length_label(radius),
+                ),
+                line_label(
+                    (ex, 0.0),
+                    (ex, -h),
+                    (0.0, 0.0),
+                    question.length_label(height),
+                ),
+            ];
+            (parts, labels)
+        }
+        Solid::Cone {
+            radius,
+            height,
+            slant,
+        } => {
+            let (ex, _) = ellipse_axes(radius as f64);
+            let apex = (0.0, -(height as f64));
+            let mut parts = vec![
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: radius as f64,
+                    half: Half::Back,
+                },
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: radius as f64,
+                    half: Half::Front,
+                },
+                edge((-ex, 0.0), apex),
+                edge((ex, 0.0), apex),
+                measure((0.0, 0.0), (ex, 0.0)),
+            ];
+            let mut labels = vec![line_label(
+                (0.0, 0.0),
+                (ex, 0.0),
+                (ex / 2.0, -1.0),
+                question.length_label(radius),
+            )];
+            if surface_area {
+                labels.push(line_label(
+                    (ex, 0.0),
+                    apex,
+                    (0.0, 0.0),
+                    question.length_label(slant),
+                ));
+            } else {
+                parts.push(measure((0.0, 0.0), apex));
+                labels.push(line_label(
+                    (0.0, 0.0),
+                    apex,
+                    (1.0, apex.1 / 2.0),
+                    question.length_label(height),
+                ));
+            }
+            (parts, labels)
+        }
+        Solid::Pyramid {
+            side,
+            height,
+            slant,
+        } => {
+            // The base is turned a little, so the height doesn't fall on top
+            // of the front edge
+            let half_diagonal = side as f64 / 2f64.sqrt();
+            let corner = |k: u32| {
+                let angle = (20.0 + 90.0 * k as f64).to_radians();
+                project(
+                    half_diagonal * angle.cos(),
+                    half_diagonal * angle.sin(),
+                    0.0,
+                )
+            };
+            // Front, left, back and right corner as seen on the screen
+            let (front, left, back, right) = (corner(0), corner(1), corner(2), corner(3));
+            let apex = project(0.0, 0.0, height as f64);
+            let mut parts = vec![
+                hidden(left, back),
+                hidden(back, right),
+                hidden(back, apex),
+                edge(left, front),
+                edge(front, right),
+                edge(left, apex),
+                edge(front, apex),
+                edge(right, apex),
+            ];
+            let mut labels = vec![line_label(
+                left,
+                front,
+                (0.0, 0.0),
+                question.length_label(side),
+            )];
+            if surface_area {
+                let foot = midpoint(front, right);
+                parts.push(measure(apex, foot));
+                labels.push(line_label(apex, foot, front, question.length_label(slant)));
+            } else {
+                parts.push(measure((0.0, 0.0), apex));
+                labels.push(line_label(
+                    (0.0, 0.0),
+                    apex,
+                    (1.0, apex.1 / 2.0),
+                    question.length_label(height),
+                ));
+            }
+            (parts, labels)
+        }
+        Solid::Sphere { radius } => {
+            let (ex, _) = ellipse_axes(radius as f64);
+            let parts = vec![
+                Part::Circle {
+                    center: (0.0, 0.0),
+                    radius: ex,
+                },
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: radius as f64,
+                    half: Half::Back,
+                },
+                Part::Ellipse {
+                    center: (0.0, 0.0),
+                    radius: radius as f64,
+                    half: Half::Front,
+                },
+                measure((0.0, 0.0), (ex, 0.0)),
+            ];
+            let labels = vec![line_label(
+                (0.0, 0.0),
+                (ex, 0.0),
+                (ex / 2.0, 1.0),
+                question.length_label(radius),
+            )];
+            (parts, labels)
+        }
+    }
+}
+
+// A box with its back corner at the origin. A cube only needs one edge labelled.
+fn cuboid(
+    question: &SolidQuestion,
+    length: u32,
+    width: u32,
+    height: u32,
+    cube: bool,
+) -> (Vec<Part>, Vec<Label>) {
+    let (l, w, h) = (length as f64, width as f64, height as f64);
+    let p = |x: f64, y: f64, z: f64| project(x * l, y * w, z * h);
+    let center = p(0.5, 0.5, 0.5);
+    let parts = vec![
+        hidden(p(0.0, 0.0, 0.0), p(1.0, 0.0, 0.0)),
+        hidden(p(0.0, 0.0, 0.0), p(0.0, 1.0, 0.0)),
+        hidden(p(0.0, 0.0, 0.0), p(0.0, 0.0, 1.0)),
+        edge(p(0.0, 0.0, 1.0), p(1.0, 0.0, 1.0)),
+        edge(p(1.0, 0.0, 1.0), p(1.0, 1.0, 1.0)),
+        edge(p(1.0, 1.0, 1.0), p(0.0, 1.0, 1.0)),
+        edge(p(0.0, 1.0, 1.0), p(0.0, 0.0, 1.0)),
+        edge(p(1.0, 0.0, 0.0), p(1.0, 0.0, 1.0)),
+        edge(p(1.0, 1.0, 0.0), p(1.0, 1.0, 1.0)),
+        edge(p(0.0, 1.0, 0.0), p(0.0, 1.0, 1.0)),
+        edge(p(1.0, 0.0, 0.0), p(1.0, 1.0, 0.0)),
+        edge(p(1.0, 1.0, 0.0), p(0.0, 1.0, 0.0)),
+    ];
+    let mut labels = vec![line_label(
+        p(0.0, 1.0, 0.0),
+        p(1.0, 1.0, 0.0),
+        center,
+        question.length_label(length),
+    )];
+    if !cube {
+        labels.push(line_label(
+            p(1.0, 0.0, 0.0),
+            p(1.0, 1.0, 0.0),
+            center,
+            question.length_label(width),
+        ));
+        labels.push(line_label(
+            p(1.0, 0.0, 0.0),
+            p(1.0, 0.0, 1.0),
+            center,
+            question.length_label(height),
+        ));
+    }
+    (parts, labels)
+}
+
+// Draws the solid in isometric view, scaled to fit, with its measurements
+#[function_component(SolidDrawing)]
+pub fn solid_drawing(props: &SolidDrawingProps) -> Html {
+    let size = props.size.unwrap_or(220) as f64;
+    let (parts, labels) = drawing(&props.question);
+
+    // The corners of the box around every part
+    let extents = parts
+        .iter()
+        .flat_map(|part| match *part {
+            Part::Line { from, to, .. } => vec![from, to],
+            Part::Ellipse { center, radius, .. } => {
+                let (ex, ey) = ellipse_axes(radius);
+                vec![
+                    (center.0 - ex, center.1 - ey),
+                    (center.0 + ex, center.1 + ey),
+                ]
+            }
+            Part::Circle { center, radius } => vec![
+                (center.0 - radius, center.1 - radius),
+                (center.0 + radius, center.1 + radius),
+            ],
+        })
+        .collect::<Vec<_>>();
+    let min_x = extents.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let max_x = extents
+        .iter()
+        .map(|p| p.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = extents.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let max_y = extents
+        .iter()
+        .map(|p| p.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let scale = size / (max_x - min_x).max(max_y - min_y).max(f64::EPSILON);
+    let width = (max_x - min_x) * scale + 2.0 * MARGIN;
+    let height = (max_y - min_y) * scale + 2.0 * MARGIN;
+    let to_px = |(x, y): Point| ((x - min_x) * scale + MARGIN, (y - min_y) * scale + MARGIN);
+
+    let shapes = parts
+        .iter()
+        .map(|part| match *part {
+            Part::Line { from, to, class } => {
+                let ((x1, y1), (x2, y2)) = (to_px(from), to_px(to));
+                html! {
+                    <line
+                        class={class}
+                        x1={format!("{:.1}", x1)} y1={format!("{:.1}", y1)}
+                        x2={format!("{:.1}", x2)} y2={format!("{:.1}", y2)}
+                    />
+                }
+            }
+            Part::Ellipse {
+                center,
+                radius,
+                ref half,
+            } => {
+                let (cx, cy) = to_px(center);
+                let (ex, ey) = ellipse_axes(radius);
+                let (rx, ry) = (ex * scale, ey * scale);
+                // The front half runs below the centre, from the left end to the right
+                let (sweep, class) = match half {
+                    Half::Whole => {
+                        return html! {
+                            <ellipse
+                                class="solid-edge"
+                                cx={format!("{:.1}", cx)} cy={format!("{:.1}", cy)}
+                                rx={format!("{:.1}", rx)} ry={format!("{:.1}", ry)}
+                            />
+                        };
+                    }
+                    Half::Front => (0, "solid-edge"),
+                    Half::Back => (1, "solid-hidden"),
+                };
+                html! {
+                    <path
+                        class={class}
+                        d={format!(
+                            "M {:.1} {:.1} A {:.1} {:.1} 0 0 {} {:.1} {:.1}",
+                            cx - rx, cy, rx, ry, sweep, cx + rx, cy
+                        )}
+                    />
+                }
+            }
+            Part::Circle { center, radius } => {
+                let (cx, cy) = to_px(center);
+                html! {
+                    <circle
+                        class="solid-edge"
+                        cx={format!("{:.1}", cx)} cy={format!("{:.1}", cy)}
+                        r={format!("{:.1}", radius * scale)}
+                    />
+                }
+            }
+        })
+        .collect::<Html>();
+
+    let labels = labels
+        .iter()
+        .map(|label| {
+            let (x, y) = to_px(label.at);
+            let (nx, ny) = label.direction;
+            // Labels grow away from their line, so long ones don't cross it
+            let anchor = if nx > 0.5 {
+                "start"
+            } else if nx < -0.5 {
+                "end"
+            } else {
+                "middle"
+            };
+            html! {
+                <text
+                    x={format!("{:.1}", x + nx * LABEL_OFFSET)}
+                    y={format!("{:.1}", y + ny * LABEL_OFFSET)}
+                    class="solid-label"
+                    text-anchor={anchor}
+                    dominant-baseline="middle"
+                >
+                    {&label.text}
+                </text>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <svg
+            class="solid-drawing"
+            width={format!("{:.0}", width)}
+            height={format!("{:.0}", height)}
+            viewBox={format!("0 0 {:.1} {:.1}", width, height)}
+        >
+            {shapes}
+            {labels}
+        </svg>
+    }
+}
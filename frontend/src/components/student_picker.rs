@@ -0,0 +1,76 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct StudentPickerProps {
+    pub students: Vec<String>, // Names that have played in this browser before
+    pub on_pick: Callback<String>,
+}
+
+// Asks who is playing, so that students sharing a computer each keep their own
+// leaderboard entries. Earlier names are picked with one click.
+#[function_component(StudentPicker)]
+pub fn student_picker(props: &StudentPickerProps) -> Html {
+    let name = use_state(String::new);
+    let error = use_state(|| false);
+
+    let on_input = {
+        let name = name.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            name.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let name = name.clone();
+        let error = error.clone();
+        let on_pick = props.on_pick.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let picked = (*name).trim().to_string();
+            if picked.is_empty() {
+                error.set(true);
+                return;
+            }
+            error.set(false);
+            name.set(String::new());
+            on_pick.emit(picked);
+        })
+    };
+
+    html! {
+        <div class="school-access student-picker">
+            <h4>{"Vem är du?"}</h4>
+            if !props.students.is_empty() {
+                <div class="known-students">
+                    { for props.students.iter().map(|student| {
+                        let on_pick = props.on_pick.clone();
+                        let student = student.clone();
+                        html! {
+                            <button onclick={Callback::from({
+                                let student = student.clone();
+                                move |_| on_pick.emit(student.clone())
+                            })}>
+                                { student }
+                            </button>
+                        }
+                    }) }
+                </div>
+            }
+            <form onsubmit={on_submit}>
+                <input
+                    type="text"
+                    placeholder="Ditt namn"
+                    maxlength="100"
+                    value={(*name).clone()}
+                    oninput={on_input}
+                />
+                <button type="submit">{"Fortsätt"}</button>
+            </form>
+            if *error {
+                <p class="status-message error">{"Ange ditt namn"}</p>
+            }
+        </div>
+    }
+}
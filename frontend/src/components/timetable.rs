@@ -0,0 +1,25 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TimetableProps {
+    pub rows: Vec<(&'static str, String)>,
+}
+
+// Times of day in a table with a caption on each row, like a timetable
+#[function_component(Timetable)]
+pub fn timetable(props: &TimetableProps) -> Html {
+    html! {
+        <table class="timetable">
+            <tbody>
+                {
+                    props.rows.iter().map(|(caption, time)| html! {
+                        <tr>
+                            <th>{ *caption }</th>
+                            <td>{ time }</td>
+                        </tr>
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+    }
+}
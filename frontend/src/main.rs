@@ -1,18 +1,24 @@
 mod api;
 pub mod components;
-mod quiz;
-mod quizzes;
-mod util;
-use common::User;
+mod storage;
+use common::custom_quiz::CustomQuiz;
+use common::quiz::*;
+use common::{FinishSessionRequest, School, StartSessionRequest, StartSessionResponse, User};
+use components::CustomQuizBuilder;
+use components::CustomQuizLoad;
+use components::DifficultySelect;
 use components::Leaderboard;
+use components::PracticeFeedback;
+use components::PracticeResult;
 use components::QuizSelect;
 use components::QuizSession;
 use components::ResultSection;
+use components::SchoolAccess;
+use components::StudentPicker;
 use components::TopUsers;
 use gloo_timers::callback::Interval;
-use quiz::*;
 use std::rc::Rc;
-use uuid::Uuid;
+use wasm_bindgen_futures::spawn_local;
 use web_sys::console;
 use web_time::{Duration, Instant};
 use yew::functional::*;
@@ -23,39 +29,32 @@ enum AppState {
     Selection,
     Quiz,
     Result(bool, Duration), // (passed, time_taken)
+    PracticeResult,
+    CustomQuizBuilder,
+}
+
+// The share code in a link like https://example.se/?quiz=K7XQ2M
+fn custom_quiz_code_from_url() -> Option<String> {
+    let search = gloo::utils::window().location().search().ok()?;
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("quiz="))
+        .map(|code| code.trim().to_uppercase())
+        .filter(|code| !code.is_empty())
 }
 
 // Main application component
 #[function_component(App)]
 fn app() -> Html {
-    const DEV_UUID: Uuid = uuid::uuid!("26493f93-6de3-4376-94b3-c8ec2dba1dc4");
-    let mut dummy_user = User::new_dummy();
-    dummy_user.school_id = DEV_UUID;
-    let all_courses = Rc::new(vec![
-        Quiz::NoCourse,
-        Quiz::Addition1_10,
-        Quiz::Addition100,
-        Quiz::Subtraction1_10,
-        Quiz::Multiplication1_10,
-        Quiz::Multiplication1_20,
-        Quiz::Division1_10,
-        Quiz::Area,
-        Quiz::Circumference,
-        Quiz::FirstOrderEquation,
-        Quiz::FirstDegreeDerivativeQuestion,
-        Quiz::NumberComparison,
-        Quiz::FractionComparison,
-        Quiz::Rounding,
-        Quiz::Average,
-        Quiz::Median,
-        Quiz::FractionToDegree,
-        Quiz::PercentChange,
-        Quiz::Expression,
-        Quiz::NegativeValues,
-        Quiz::ClockReading,
-        Quiz::ClockReadingSimple,
-        Quiz::RomanNumerals,
-    ]);
+    let student = use_state(storage::load_current_student);
+    let school = use_state(storage::load_school);
+    // Runs go on the leaderboard once both the school and the student are known
+    let user = (*school)
+        .as_ref()
+        .zip((*student).as_ref())
+        .map(|(school, (name, student_id))| User::for_school(school, name, *student_id));
+    let all_courses = Rc::new(ALL_COURSES.to_vec());
 
     let course = use_state(|| Quiz::NoCourse);
     let app_state = use_state(|| AppState::Selection);
@@ -65,18 +64,98 @@ fn app() -> Html {
     let elapsed_time = use_state(|| Duration::from_secs(0));
     let interval_ref = use_mut_ref(|| None::<Interval>);
     let failed_question_details = use_state(|| None::<(QuestionBox, String)>);
+    // Server-issued session for leaderboard runs, and the time the server measured for it
+    let session = use_state(|| None::<StartSessionResponse>);
+    let given_answers = use_state(Vec::<String>::new);
+    let verified_time = use_state(|| None::<f64>);
+    let difficulty = use_state(Difficulty::default);
+    // Practice runs continue after mistakes and are never submitted
+    let practice = use_state(|| false);
+    let mistakes = use_state(Vec::<(QuestionBox, String)>::new);
+    let practice_feedback = use_state(|| None::<(QuestionBox, String)>);
+    // A teacher's quiz loaded by its share code. It replaces the selected course.
+    let custom_quiz = use_state(|| None::<CustomQuiz>);
+
+    // School login/logout handlers
+    let on_school = {
+        let school = school.clone();
+        Callback::from(move |new_school: School| {
+            storage::save_school(&new_school);
+            school.set(Some(new_school));
+        })
+    };
+
+    let on_change_school = {
+        let school = school.clone();
+        Callback::from(move |_| {
+            storage::clear_school();
+            school.set(None);
+        })
+    };
+
+    let on_pick_student = {
+        let student = student.clone();
+        Callback::from(move |name: String| {
+            student.set(Some(storage::pick_student(&name)));
+        })
+    };
+
+    let on_change_student = {
+        let student = student.clone();
+        Callback::from(move |_| {
+            storage::clear_current_student();
+            student.set(None);
+        })
+    };
 
     // Course selection handler
     let on_course_change = {
         let course = course.clone();
+        let custom_quiz = custom_quiz.clone();
         Callback::from(move |new_course: Quiz| {
+            custom_quiz.set(None);
             course.set(new_course);
         })
     };
 
-    // Start quiz handler
-    let on_start_quiz = {
+    let on_custom_quiz = {
         let course = course.clone();
+        let custom_quiz = custom_quiz.clone();
+        let app_state = app_state.clone();
+        Callback::from(move |loaded: CustomQuiz| {
+            course.set(Quiz::NoCourse);
+            custom_quiz.set(Some(loaded));
+            app_state.set(AppState::Selection);
+        })
+    };
+
+    // Opens the quiz from a shared link once, when the page loads
+    {
+        let on_custom_quiz = on_custom_quiz.clone();
+        use_effect_with((), move |_| {
+            if let Some(code) = custom_quiz_code_from_url() {
+                spawn_local(async move {
+                    match api::fetch_custom_quiz(&code).await {
+                        Ok(loaded) => on_custom_quiz.emit(loaded),
+                        Err(e) => web_sys::console::log_1(
+                            &format!("Could not load custom quiz {}: {}", code, e).into(),
+                        ),
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    let on_open_builder = {
+        let app_state = app_state.clone();
+        Callback::from(move |_| {
+            app_state.set(AppState::CustomQuizBuilder);
+        })
+    };
+
+    // Starts the timer on a new set of questions
+    let begin_quiz = {
         let app_state = app_state.clone();
         let questions = questions.clone();
         let current_question = current_question.clone();
@@ -84,60 +163,200 @@ fn app() -> Html {
         let elapsed_time = elapsed_time.clone();
         let interval_ref = interval_ref.clone();
 
-        Callback::from(move |_| {
-            if *course != Quiz::NoCourse {
-                web_sys::console::log_1(
-                    &format!(
-                        "on_start_quiz START: elapsed_time BEFORE reset: {:?}",
-                        *elapsed_time
-                    )
-                    .into(),
-                );
+        Callback::from(move |new_questions: Vec<QuestionBox>| {
+            web_sys::console::log_1(
+                &format!(
+                    "on_start_quiz START: elapsed_time BEFORE reset: {:?}",
+                    *elapsed_time
+                )
+                .into(),
+            );
 
-                let number_questions_for_quiz = course.number_of_questions();
-                if number_questions_for_quiz == 0 {
-                    web_sys::console::log_1(&"Cannot start quiz: 0 questions configured.".into());
-                    return;
-                }
+            questions.set(new_questions);
+            current_question.set(0);
 
-                // Generate questions based on selected course
-                let generated_questions =
-                    generate_questions(*course.clone(), number_questions_for_quiz);
-                questions.set(generated_questions);
-                current_question.set(0);
+            // Reset timer
+            let quiz_start_instant = Instant::now();
+            start_time_state_handle.set(quiz_start_instant);
+            elapsed_time.set(Duration::from_secs(0));
 
-                // Reset timer
-                let quiz_start_instant = Instant::now();
-                start_time_state_handle.set(quiz_start_instant);
-                elapsed_time.set(Duration::from_secs(0));
+            web_sys::console::log_2(
+                &format!(
+                    "on_start_quiz MID: start_time state set to: {:?}",
+                    quiz_start_instant
+                )
+                .into(),
+                &format!(
+                    "on_start_quiz MID: elapsed_time reset to: {:?}",
+                    *elapsed_time
+                )
+                .into(),
+            );
 
-                web_sys::console::log_2(
-                    &format!(
-                        "on_start_quiz MID: start_time state set to: {:?}",
-                        quiz_start_instant
-                    )
-                    .into(),
-                    &format!(
-                        "on_start_quiz MID: elapsed_time reset to: {:?}",
-                        *elapsed_time
-                    )
-                    .into(),
-                );
+            // Start timer interval
+            let elapsed = elapsed_time.clone();
+            let timer_interval = Interval::new(100, move || {
+                let now = Instant::now();
+                let duration = now.duration_since(quiz_start_instant);
+                elapsed.set(duration);
+            });
+            interval_ref.borrow_mut().replace(timer_interval);
+            web_sys::console::log_1(&"on_start_quiz END: New timer created and stored.".into());
+            app_state.set(AppState::Quiz);
+        })
+    };
 
-                // Start timer interval
-                let elapsed = elapsed_time.clone();
-                let timer_interval = Interval::new(100, move || {
-                    let now = Instant::now();
-                    let duration = now.duration_since(quiz_start_instant);
-                    elapsed.set(duration);
+    let on_difficulty_change = {
+        let difficulty = difficulty.clone();
+        Callback::from(move |new_difficulty: Difficulty| {
+            difficulty.set(new_difficulty);
+        })
+    };
+
+    // Start quiz handler
+    let on_start_quiz = {
+        let course = course.clone();
+        let user = user.clone();
+        let session = session.clone();
+        let given_answers = given_answers.clone();
+        let verified_time = verified_time.clone();
+        let practice = practice.clone();
+        let difficulty = difficulty.clone();
+        let custom_quiz = custom_quiz.clone();
+        let begin_quiz = begin_quiz.clone();
+
+        Callback::from(move |_| {
+            let quiz = *course;
+            let custom_quiz = (*custom_quiz).clone();
+            if quiz == Quiz::NoCourse && custom_quiz.is_none() {
+                return;
+            }
+            // A custom quiz plays on the difficulty the teacher chose
+            let (course_id, difficulty, number_of_questions) = match &custom_quiz {
+                Some(custom_quiz) => (
+                    custom_quiz.course_id(),
+                    custom_quiz.difficulty,
+                    custom_quiz.number_of_questions(),
+                ),
+                None => (quiz.course_id(), *difficulty, quiz.number_of_questions()),
+            };
+            if number_of_questions == 0 {
+                web_sys::console::log_1(&"Cannot start quiz: 0 questions configured.".into());
+                return;
+            }
+            let questions_for_seed = move |seed: u64| match &custom_quiz {
+                Some(custom_quiz) => custom_quiz.generate_questions(seed),
+                None => generate_questions(quiz, number_of_questions, seed, difficulty),
+            };
+
+            given_answers.set(Vec::new());
+            verified_time.set(None);
+            session.set(None);
+            practice.set(false);
+
+            // Ask the server for a session so the run can go on the leaderboard.
+            // The questions come from the session's seed so the server can grade them.
+            if let Some(user) = &user {
+                let req = StartSessionRequest {
+                    course: course_id,
+                    difficulty,
+                    school_id: user.school_id,
+                    student_id: user.student_id,
+                };
+                let session = session.clone();
+                let begin_quiz = begin_quiz.clone();
+                spawn_local(async move {
+                    match api::start_session(&req).await {
+                        Ok(started) => {
+                            let seed = started.seed;
+                            session.set(Some(started));
+                            begin_quiz.emit(questions_for_seed(seed));
+                        }
+                        Err(e) => {
+                            web_sys::console::log_1(
+                                &format!("Could not start a quiz session: {}", e).into(),
+                            );
+                            begin_quiz.emit(questions_for_seed(random_seed()));
+                        }
+                    }
                 });
-                interval_ref.borrow_mut().replace(timer_interval);
-                web_sys::console::log_1(&"on_start_quiz END: New timer created and stored.".into());
-                app_state.set(AppState::Quiz);
+            } else {
+                begin_quiz.emit(questions_for_seed(random_seed()));
+            }
+        })
+    };
+
+    // Start practice handler. Practice runs don't need a session from the server.
+    let on_start_practice = {
+        let course = course.clone();
+        let session = session.clone();
+        let verified_time = verified_time.clone();
+        let practice = practice.clone();
+        let mistakes = mistakes.clone();
+        let practice_feedback = practice_feedback.clone();
+        let difficulty = difficulty.clone();
+        let custom_quiz = custom_quiz.clone();
+        let begin_quiz = begin_quiz.clone();
+
+        Callback::from(move |_| {
+            let quiz = *course;
+            if quiz == Quiz::NoCourse && custom_quiz.is_none() {
+                return;
+            }
+
+            session.set(None);
+            verified_time.set(None);
+            practice.set(true);
+            mistakes.set(Vec::new());
+            practice_feedback.set(None);
+            begin_quiz.emit(match &*custom_quiz {
+                Some(custom_quiz) => custom_quiz.generate_questions(random_seed()),
+                None => {
+                    generate_questions(quiz, quiz.number_of_questions(), random_seed(), *difficulty)
+                }
+            });
+        })
+    };
+
+    // Moves a practice run to the next question, or to the summary after the last one
+    let advance_practice = {
+        let questions = questions.clone();
+        let current_question = current_question.clone();
+        let app_state = app_state.clone();
+        let interval_ref = interval_ref.clone();
+        let practice_feedback = practice_feedback.clone();
+
+        Callback::from(move |_: ()| {
+            practice_feedback.set(None);
+            let next_question = *current_question + 1;
+            if next_question >= (*questions).len() {
+                if let Some(handle) = interval_ref.borrow_mut().take() {
+                    handle.cancel();
+                }
+                app_state.set(AppState::PracticeResult);
+            } else {
+                current_question.set(next_question);
             }
         })
     };
 
+    // Practices only the questions that were missed in the last practice run
+    let on_retry_missed = {
+        let mistakes = mistakes.clone();
+        let practice_feedback = practice_feedback.clone();
+        let begin_quiz = begin_quiz.clone();
+
+        Callback::from(move |_| {
+            let missed_questions = (*mistakes)
+                .iter()
+                .map(|(question, _)| question.clone())
+                .collect::<Vec<_>>();
+            mistakes.set(Vec::new());
+            practice_feedback.set(None);
+            begin_quiz.emit(missed_questions);
+        })
+    };
+
     let on_answer = {
         let questions = questions.clone();
         let current_question = current_question.clone();
@@ -145,6 +364,13 @@ fn app() -> Html {
         let elapsed_time = elapsed_time.clone();
         let interval_ref = interval_ref.clone();
         let failed_question_details = failed_question_details.clone();
+        let session = session.clone();
+        let given_answers = given_answers.clone();
+        let verified_time = verified_time.clone();
+        let practice = practice.clone();
+        let mistakes = mistakes.clone();
+        let practice_feedback = practice_feedback.clone();
+        let advance_practice = advance_practice.clone();
 
         Callback::from(move |answer: String| {
             let current_q = *current_question;
@@ -153,6 +379,26 @@ fn app() -> Html {
                 // Should not happen if on_start_quiz has guards
                 return;
             }
+
+            // In practice a wrong answer shows the correct one, then the run goes on
+            if *practice {
+                if let Some(q) = (*questions).get(current_q) {
+                    if q.check_answer(&answer) {
+                        advance_practice.emit(());
+                    } else {
+                        let mut all_mistakes = (*mistakes).clone();
+                        all_mistakes.push((q.clone(), answer.clone()));
+                        mistakes.set(all_mistakes);
+                        practice_feedback.set(Some((q.clone(), answer)));
+                    }
+                }
+                return;
+            }
+
+            let mut answers_so_far = (*given_answers).clone();
+            answers_so_far.push(answer.clone());
+            given_answers.set(answers_so_far.clone());
+
             // Ensure we don't panic if questions isn't populated somehow
             if let Some(q) = (*questions).get(current_q) {
                 if q.check_answer(&answer) {
@@ -163,6 +409,28 @@ fn app() -> Html {
                         }
                         failed_question_details.set(None);
                         app_state.set(AppState::Result(true, *elapsed_time));
+
+                        // The leaderboard only takes the time measured by the server
+                        if let Some(started) = &*session {
+                            let req = FinishSessionRequest {
+                                session_id: started.session_id,
+                                answers: answers_so_far,
+                            };
+                            let verified_time = verified_time.clone();
+                            spawn_local(async move {
+                                match api::finish_session(&req).await {
+                                    Ok(finished) if finished.passed => {
+                                        verified_time.set(Some(finished.time_seconds))
+                                    }
+                                    Ok(_) => web_sys::console::log_1(
+                                        &"The server did not accept the answers.".into(),
+                                    ),
+                                    Err(e) => web_sys::console::log_1(
+                                        &format!("Could not finish the quiz session: {}", e).into(),
+                                    ),
+                                }
+                            });
+                        }
                     } else {
                         current_question.set(current_q + 1);
                     }
@@ -193,6 +461,11 @@ fn app() -> Html {
         let current_question = current_question.clone();
         let elapsed_time = elapsed_time.clone();
         let failed_question_details = failed_question_details.clone();
+        let session = session.clone();
+        let verified_time = verified_time.clone();
+        let practice = practice.clone();
+        let mistakes = mistakes.clone();
+        let practice_feedback = practice_feedback.clone();
 
         Callback::from(move |_| {
             if let Some(handle) = interval_ref.borrow_mut().take() {
@@ -202,6 +475,11 @@ fn app() -> Html {
             current_question.set(0);
             elapsed_time.set(Duration::from_secs(0));
             failed_question_details.set(None);
+            session.set(None);
+            verified_time.set(None);
+            practice.set(false);
+            mistakes.set(Vec::new());
+            practice_feedback.set(None);
             app_state.set(AppState::Selection);
         })
     };
@@ -215,8 +493,16 @@ fn app() -> Html {
         let current_question = current_question.clone();
         let elapsed_time = elapsed_time.clone();
         let failed_question_details = failed_question_details.clone();
+        let session = session.clone();
+        let verified_time = verified_time.clone();
+        let practice = practice.clone();
+        let mistakes = mistakes.clone();
+        let practice_feedback = practice_feedback.clone();
 
-        use_effect_with(*course, move |_current_course| {
+        let custom_quiz_code = (*custom_quiz)
+            .as_ref()
+            .map(|custom_quiz| custom_quiz.code.clone());
+        use_effect_with((*course, custom_quiz_code), move |_current_course| {
             if *app_state != AppState::Selection {
                 web_sys::console::log_1(
                     &"Course changed, resetting state and stopping timer.".into(),
@@ -231,18 +517,35 @@ fn app() -> Html {
                 current_question.set(0);
                 elapsed_time.set(Duration::from_secs(0));
                 failed_question_details.set(None);
+                session.set(None);
+                verified_time.set(None);
+                practice.set(false);
+                mistakes.set(Vec::new());
+                practice_feedback.set(None);
                 app_state.set(AppState::Selection);
             }
             || ()
         });
     }
 
-    let allow_submission = matches!(*app_state, AppState::Result(true, _));
-
+    // Only runs with a time verified by the server can be submitted
     let current_user_time = match *app_state {
-        AppState::Result(true, time_taken) => Some(time_taken.as_secs_f64()),
+        AppState::Result(true, _) => *verified_time,
         _ => None,
     };
+    let allow_submission = current_user_time.is_some();
+    let session_id = (*session).as_ref().map(|started| started.session_id);
+
+    // Custom quizzes have their own leaderboard, on the difficulty the teacher chose
+    let (leaderboard_course, leaderboard_title, leaderboard_difficulty) = match &*custom_quiz {
+        Some(custom_quiz) => (
+            custom_quiz.course_id(),
+            custom_quiz.title.clone(),
+            custom_quiz.difficulty,
+        ),
+        None => (course.to_string(), course.to_string(), *difficulty),
+    };
+    let nothing_selected = *course == Quiz::NoCourse && custom_quiz.is_none();
 
     html! {
         <div class="app-container">
@@ -252,36 +555,107 @@ fn app() -> Html {
                     selected={*course}
                     on_change={on_course_change}
                 />
+                <CustomQuizLoad on_load={on_custom_quiz.clone()} />
+                if user.is_some() {
+                    <button class="create-custom-quiz" onclick={on_open_builder}>
+                        {"Skapa egen quiz"}
+                    </button>
+                }
             </div>
             <div class="main-content">
                 <div class="title-section">
                     <h1>{"Mer Matte"}</h1>
-                     <TopUsers
-                        limit={3}
-                        school_name={dummy_user.school.clone()}
-                        school_id={dummy_user.school_id}
-                    />
-
+                    {
+                        match (&*school, &user) {
+                            (Some(_), Some(user)) => html! {
+                                <>
+                                    <TopUsers
+                                        limit={3}
+                                        school_name={user.school.clone()}
+                                        school_id={user.school_id}
+                                    />
+                                    <div class="school-info">
+                                        <span>{&user.school}</span>
+                                        <button onclick={on_change_school}>{"Byt skola"}</button>
+                                        <span>{&user.name}</span>
+                                        <button onclick={on_change_student}>{"Byt elev"}</button>
+                                    </div>
+                                </>
+                            },
+                            (Some(school), None) => html! {
+                                <>
+                                    <div class="school-info">
+                                        <span>{&school.name}</span>
+                                        <button onclick={on_change_school}>{"Byt skola"}</button>
+                                    </div>
+                                    <StudentPicker
+                                        students={storage::load_students().into_keys().collect::<Vec<_>>()}
+                                        on_pick={on_pick_student}
+                                    />
+                                </>
+                            },
+                            (None, _) => html! { <SchoolAccess on_school={on_school} /> },
+                        }
+                    }
                 </div>
                 <div class="dynamic-content-wrapper">
                     {
                         match (*app_state).clone() {
                             AppState::Selection => html! {
                                 <div class="start-section">
-                                    <h2>{format!("{}", course.to_string())}</h2>
-                                    <p>{"Välj en quiz i listan och klicka på Starta quiz när du är redo."}</p>
-                                    <button
-                                        onclick={on_start_quiz}
-                                        disabled={*course == Quiz::NoCourse}
-                                    >
-                                        {"Starta quiz"}
-                                    </button>
+                                    {
+                                        match &*custom_quiz {
+                                            Some(custom_quiz) => html! {
+                                                <>
+                                                    <h2>{&custom_quiz.title}</h2>
+                                                    <p>{format!(
+                                                        "{} frågor på nivån {}. Klicka på Starta quiz när du är redo.",
+                                                        custom_quiz.number_of_questions(),
+                                                        custom_quiz.difficulty
+                                                    )}</p>
+                                                </>
+                                            },
+                                            None => html! {
+                                                <>
+                                                    <h2>{format!("{}", course.to_string())}</h2>
+                                                    <p>{"Välj en quiz i listan och klicka på Starta quiz när du är redo."}</p>
+                                                    <DifficultySelect
+                                                        selected={*difficulty}
+                                                        on_change={on_difficulty_change}
+                                                    />
+                                                </>
+                                            },
+                                        }
+                                    }
+                                    <div class="start-actions">
+                                        <button
+                                            onclick={on_start_quiz}
+                                            disabled={nothing_selected}
+                                        >
+                                            {"Starta quiz"}
+                                        </button>
+                                        <button
+                                            class="practice-button"
+                                            onclick={on_start_practice}
+                                            disabled={nothing_selected}
+                                        >
+                                            {"Öva"}
+                                        </button>
+                                    </div>
                                 </div>
                             },
                             AppState::Quiz => {
                                 let current_q = *current_question;
                                 let total_questions_for_current_quiz = (*questions).len();
-                                if current_q < (*questions).len() {
+                                if let Some((question, user_answer)) = (*practice_feedback).clone() {
+                                    html! {
+                                        <PracticeFeedback
+                                            question={question}
+                                            user_answer={user_answer}
+                                            on_next={advance_practice.clone()}
+                                        />
+                                    }
+                                } else if current_q < (*questions).len() {
                                     let question = (*questions)[current_q].clone();
                                     html! {
                                         <QuizSession
@@ -312,17 +686,49 @@ fn app() -> Html {
                                 />
                             }
                             }
+                            AppState::PracticeResult => html! {
+                                <PracticeResult
+                                    total_questions={(*questions).len()}
+                                    mistakes={(*mistakes).clone()}
+                                    on_retry_missed={on_retry_missed.clone()}
+                                    on_restart={on_restart.clone()}
+                                />
+                            },
+                            AppState::CustomQuizBuilder => match &user {
+                                Some(user) => html! {
+                                    <CustomQuizBuilder
+                                        user={user.clone()}
+                                        on_created={on_custom_quiz.clone()}
+                                        on_cancel={on_restart.clone()}
+                                    />
+                                },
+                                None => html! {
+                                    <p>{"Logga in med skolans kod för att skapa en egen quiz."}</p>
+                                },
+                            },
                         }
                     }
                 </div>
             </div>
             <div class="leaderboard-panel">
-            <Leaderboard
-               course={(*course).to_string()}
-               user={dummy_user.clone()}
-               allow_submission={allow_submission}
-               user_time={current_user_time}
-           />
+            {
+                match &user {
+                    Some(user) => html! {
+                        <Leaderboard
+                            course={leaderboard_course}
+                            title={leaderboard_title}
+                            difficulty={leaderboard_difficulty}
+                            user={user.clone()}
+                            allow_submission={allow_submission}
+                            user_time={current_user_time}
+                            session_id={session_id}
+                        />
+                    },
+                    None => html! {
+                        <p>{"Logga in med skolans kod för att se och skicka in till topplistan."}</p>
+                    },
+                }
+            }
        </div>
         </div>
     }
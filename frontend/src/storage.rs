@@ -0,0 +1,68 @@
+use common::School;
+use gloo::storage::{LocalStorage, Storage};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+const STUDENT_ID_KEY: &str = "mer_matte.student_id";
+const STUDENTS_KEY: &str = "mer_matte.students";
+const CURRENT_STUDENT_KEY: &str = "mer_matte.current_student";
+const SCHOOL_KEY: &str = "mer_matte.school";
+
+// Students who have played in this browser, by name. A classroom computer is
+// shared, so every name keeps its own id and its own leaderboard entries.
+pub fn load_students() -> BTreeMap<String, Uuid> {
+    LocalStorage::get(STUDENTS_KEY).unwrap_or_default()
+}
+
+// The student who is playing now, as picked the last time
+pub fn load_current_student() -> Option<(String, Uuid)> {
+    let name: String = LocalStorage::get(CURRENT_STUDENT_KEY).ok()?;
+    load_students().remove_entry(&name)
+}
+
+// Switches to the student with this name, ignoring case, or adds a new one.
+// The first student takes over the id from when there was one per browser,
+// so earlier leaderboard entries are kept.
+pub fn pick_student(name: &str) -> (String, Uuid) {
+    let mut students = load_students();
+    let existing = students
+        .iter()
+        .find(|(known, _)| known.to_lowercase() == name.to_lowercase())
+        .map(|(known, student_id)| (known.clone(), *student_id));
+    let (name, student_id) = existing.unwrap_or_else(|| {
+        let student_id = match LocalStorage::get::<Uuid>(STUDENT_ID_KEY) {
+            Ok(student_id) if students.is_empty() => student_id,
+            _ => Uuid::new_v4(),
+        };
+        (name.to_string(), student_id)
+    });
+    students.insert(name.clone(), student_id);
+
+    match LocalStorage::set(STUDENTS_KEY, &students)
+        .and_then(|_| LocalStorage::set(CURRENT_STUDENT_KEY, &name))
+    {
+        Ok(()) => LocalStorage::delete(STUDENT_ID_KEY),
+        Err(e) => web_sys::console::log_1(&format!("Could not store student: {}", e).into()),
+    }
+    (name, student_id)
+}
+
+pub fn clear_current_student() {
+    LocalStorage::delete(CURRENT_STUDENT_KEY);
+}
+
+// The school is remembered after its access code has been redeemed once,
+// so students don't have to type the code on every visit.
+pub fn load_school() -> Option<School> {
+    LocalStorage::get::<School>(SCHOOL_KEY).ok()
+}
+
+pub fn save_school(school: &School) {
+    if let Err(e) = LocalStorage::set(SCHOOL_KEY, school) {
+        web_sys::console::log_1(&format!("Could not store school: {}", e).into());
+    }
+}
+
+pub fn clear_school() {
+    LocalStorage::delete(SCHOOL_KEY);
+}